@@ -16,7 +16,7 @@
 
 use crate::error::Error;
 use fish_shoal_gui::FishShoalGui;
-use fish_shoal_simulator::{Config, FishShoalSimulator, SimulatorOutput};
+use fish_shoal_simulator::{Command, Config, FishShoalSimulator, SimulatorOutput, MAX_SPECIES};
 use std::{
     sync::mpsc::{self, Receiver, Sender},
     thread::{self, JoinHandle},
@@ -34,24 +34,36 @@ impl FishShoalApp {
         let (data_sender, data_receiver): (Sender<SimulatorOutput>, Receiver<SimulatorOutput>) =
             mpsc::channel::<SimulatorOutput>();
 
-        let gui: FishShoalGui = FishShoalGui::new(data_receiver, cfg_sender);
+        let (command_sender, command_receiver): (Sender<Command>, Receiver<Command>) =
+            mpsc::channel::<Command>();
+
+        let gui: FishShoalGui = FishShoalGui::new(data_receiver, cfg_sender, command_sender);
 
         let sim_thread: JoinHandle<Result<(), Error>> = thread::spawn(move || {
-            loop {
-                let cfg: Config = match cfg_receiver.recv() {
-                    Ok(cfg) => cfg,
-                    Err(_) => break,
-                };
+            while let Ok(mut cfg) = cfg_receiver.recv() {
+                for command in command_receiver.try_iter() {
+                    let restores: bool = matches!(command, Command::Restore(_));
+                    sim.execute(command).map_err(Error::Simulator)?;
+                    if restores {
+                        cfg = *sim.config();
+                    }
+                }
 
                 let data_sender: Sender<SimulatorOutput> = data_sender.clone();
 
                 sim.run(move |output: SimulatorOutput| {
+                    let captured: [usize; MAX_SPECIES] = output.captured;
                     if data_sender.send(output).is_err() {
                         return Config {
                             running: false,
                             ..Default::default()
                         };
                     }
+
+                    let mut cfg: Config = cfg;
+                    for (species, captured) in cfg.species.iter_mut().zip(captured) {
+                        species.count = species.count.saturating_sub(captured);
+                    }
                     cfg
                 })
                 .map_err(Error::Simulator)?;
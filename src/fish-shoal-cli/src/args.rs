@@ -0,0 +1,111 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{error::Error, exporter::Format};
+use std::{num::ParseIntError, path::PathBuf};
+
+#[derive(Debug)]
+pub struct Args {
+    pub config: Option<PathBuf>,
+    pub output: Option<PathBuf>,
+    pub format: Format,
+    pub seed: Option<u64>,
+    pub steps: usize,
+    pub every: usize,
+    pub dt: f32,
+}
+
+impl Args {
+    pub const USAGE: &'static str = "\
+Usage: fish-shoal-cli [OPTIONS]
+
+Runs the fish shoal simulator without a window and exports snapshots.
+
+Options:
+  -c, --config <FILE>    Simulator configuration file (TOML or JSON), defaults otherwise
+  -o, --output <FILE>    Output file, standard output when omitted
+  -f, --format <FORMAT>  csv, jsonl or bin, guessed from the output extension
+  -s, --seed <SEED>      Seed for a reproducible run
+  -n, --steps <N>        Number of fixed steps to run [default: 1000]
+  -k, --every <K>        Export a snapshot every K steps [default: 1]
+      --dt <SECONDS>     Duration of one step [default: 0.016666668]
+  -h, --help             Print this help";
+
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Option<Self>, Error> {
+        let mut config: Option<PathBuf> = None;
+        let mut output: Option<PathBuf> = None;
+        let mut format: Option<Format> = None;
+        let mut seed: Option<u64> = None;
+        let mut steps: usize = 1_000;
+        let mut every: usize = 1;
+        let mut dt: f32 = 1.0 / 60.0;
+
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| Error::Args(format!("missing value for {arg}")))
+            };
+
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "-c" | "--config" => config = Some(PathBuf::from(value()?)),
+                "-o" | "--output" => output = Some(PathBuf::from(value()?)),
+                "-f" | "--format" => format = Some(Format::from_name(&value()?)?),
+                "-s" | "--seed" => seed = Some(Self::parse_int(&arg, &value()?)?),
+                "-n" | "--steps" => steps = Self::parse_int(&arg, &value()?)? as usize,
+                "-k" | "--every" => every = Self::parse_int(&arg, &value()?)? as usize,
+                "--dt" => {
+                    let raw: String = value()?;
+                    dt = raw
+                        .parse()
+                        .map_err(|_| Error::Args(format!("invalid value for {arg}: {raw}")))?;
+                }
+                unknown => return Err(Error::Args(format!("unknown argument {unknown}"))),
+            }
+        }
+
+        if every == 0 {
+            return Err(Error::Args("--every must be at least 1".to_string()));
+        }
+
+        if !dt.is_finite() || dt <= 0.0 {
+            return Err(Error::Args(format!("--dt must be positive, got {dt}")));
+        }
+
+        let format: Format = match format {
+            Some(format) => format,
+            None => match &output {
+                Some(path) => Format::from_path(path)?,
+                None => Format::Csv,
+            },
+        };
+
+        Ok(Some(Self {
+            config,
+            output,
+            format,
+            seed,
+            steps,
+            every,
+            dt,
+        }))
+    }
+
+    fn parse_int(arg: &str, raw: &str) -> Result<u64, Error> {
+        raw.parse()
+            .map_err(|err: ParseIntError| Error::Args(format!("invalid value for {arg}: {err}")))
+    }
+}
@@ -0,0 +1,62 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{args::Args, error::Error, exporter::Exporter};
+use fish_shoal_simulator::{Config, FishShoalSimulator, SimulatorOutput};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+};
+
+pub struct FishShoalCli;
+
+impl FishShoalCli {
+    pub fn run(args: Args) -> Result<(), Error> {
+        let cfg: Config = match &args.config {
+            Some(path) => Config::load(path).map_err(Error::Simulator)?,
+            None => Config::default(),
+        };
+
+        let mut sim: FishShoalSimulator = FishShoalSimulator::builder()
+            .config(cfg)
+            .maybe_seed(args.seed)
+            .build()
+            .map_err(Error::Simulator)?;
+
+        let writer: Box<dyn Write> = match &args.output {
+            Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+            None => Box::new(BufWriter::new(io::stdout().lock())),
+        };
+
+        let mut exporter: Exporter<Box<dyn Write>> = Exporter::new(writer, args.format)?;
+        let mut output: SimulatorOutput = SimulatorOutput::default();
+
+        sim.output(&mut output);
+        exporter.write(0, 0.0, &output)?;
+
+        for step in 1..=args.steps {
+            sim.step(args.dt).map_err(Error::Simulator)?;
+
+            if step % args.every == 0 {
+                sim.output(&mut output);
+                exporter.write(step, step as f32 * args.dt, &output)?;
+            }
+        }
+
+        exporter.finish()?;
+        Ok(())
+    }
+}
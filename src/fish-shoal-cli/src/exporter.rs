@@ -0,0 +1,282 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::error::Error;
+use fish_shoal_simulator::SimulatorOutput;
+use serde::Serialize;
+use std::{
+    ffi::OsStr,
+    io::{self, Write},
+    path::Path,
+};
+
+const BINARY_MAGIC: &[u8; 4] = b"FSSB";
+const BINARY_VERSION: u32 = 2;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Format {
+    Csv,
+    JsonLines,
+    Binary,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Result<Self, Error> {
+        match name.to_ascii_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "jsonl" | "ndjson" | "json" => Ok(Self::JsonLines),
+            "bin" | "binary" => Ok(Self::Binary),
+            _ => Err(Error::Args(format!("unknown format {name}"))),
+        }
+    }
+
+    pub fn from_path(path: &Path) -> Result<Self, Error> {
+        match path.extension().and_then(OsStr::to_str) {
+            Some(extension) => Self::from_name(extension),
+            None => Ok(Self::Csv),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Frame<'a> {
+    step: usize,
+    time: f32,
+    #[serde(flatten)]
+    output: &'a SimulatorOutput,
+}
+
+pub struct Exporter<W: Write> {
+    writer: W,
+    format: Format,
+}
+
+impl<W: Write> Exporter<W> {
+    pub fn new(mut writer: W, format: Format) -> io::Result<Self> {
+        match format {
+            Format::Csv => writeln!(
+                writer,
+                "step,time,id,x,y,vx,vy,speed,density,stress,species,goal"
+            )?,
+            Format::JsonLines => (),
+            Format::Binary => {
+                writer.write_all(BINARY_MAGIC)?;
+                writer.write_all(&BINARY_VERSION.to_le_bytes())?;
+            }
+        }
+
+        Ok(Self { writer, format })
+    }
+
+    pub fn write(&mut self, step: usize, time: f32, output: &SimulatorOutput) -> io::Result<()> {
+        match self.format {
+            Format::Csv => self.write_csv(step, time, output),
+            Format::JsonLines => self.write_json_line(step, time, output),
+            Format::Binary => self.write_binary(step, time, output),
+        }
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    fn write_csv(&mut self, step: usize, time: f32, output: &SimulatorOutput) -> io::Result<()> {
+        for idx in 0..output.len() {
+            let [x, y]: [f32; 2] = output.positions[idx];
+            let [vx, vy]: [f32; 2] = output.velocities[idx];
+            let goal: String = output.goals[idx].map_or(String::new(), |goal| goal.to_string());
+            writeln!(
+                self.writer,
+                "{step},{time},{},{x},{y},{vx},{vy},{},{},{},{},{goal}",
+                output.ids[idx],
+                output.speeds[idx],
+                output.densities[idx],
+                output.stresses[idx],
+                output.species[idx]
+            )?;
+        }
+        Ok(())
+    }
+
+    fn write_json_line(
+        &mut self,
+        step: usize,
+        time: f32,
+        output: &SimulatorOutput,
+    ) -> io::Result<()> {
+        serde_json::to_writer(&mut self.writer, &Frame { step, time, output })?;
+        writeln!(self.writer)
+    }
+
+    fn write_binary(&mut self, step: usize, time: f32, output: &SimulatorOutput) -> io::Result<()> {
+        self.writer.write_all(&(step as u64).to_le_bytes())?;
+        self.writer.write_all(&time.to_le_bytes())?;
+        self.writer
+            .write_all(&(output.len() as u32).to_le_bytes())?;
+
+        for idx in 0..output.len() {
+            let [x, y]: [f32; 2] = output.positions[idx];
+            let [vx, vy]: [f32; 2] = output.velocities[idx];
+            let goal: u8 = output.goals[idx].map_or(0, |goal| goal as u8 + 1);
+
+            self.writer
+                .write_all(&(output.ids[idx] as u64).to_le_bytes())?;
+            for value in [x, y, vx, vy, output.speeds[idx]] {
+                self.writer.write_all(&value.to_le_bytes())?;
+            }
+            self.writer
+                .write_all(&(output.densities[idx] as u32).to_le_bytes())?;
+            self.writer.write_all(&output.stresses[idx].to_le_bytes())?;
+            self.writer.write_all(&[output.species[idx] as u8, goal])?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Exporter, Format};
+    use fish_shoal_simulator::SimulatorOutput;
+    use std::path::Path;
+
+    fn mock_output() -> SimulatorOutput {
+        SimulatorOutput {
+            ids: vec![3, 8],
+            positions: vec![[1.0, 2.0], [3.5, 4.5]],
+            velocities: vec![[1.0, 0.0], [0.0, -1.0]],
+            speeds: vec![10.0, 20.0],
+            densities: vec![1, 2],
+            stresses: vec![0.1, 0.75],
+            species: vec![0, 1],
+            goals: vec![None, Some(0)],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn format_from_path() {
+        assert_eq!(Format::from_path(Path::new("a.csv")).unwrap(), Format::Csv);
+        assert_eq!(
+            Format::from_path(Path::new("a.jsonl")).unwrap(),
+            Format::JsonLines
+        );
+        assert_eq!(
+            Format::from_path(Path::new("a.bin")).unwrap(),
+            Format::Binary
+        );
+        assert!(Format::from_path(Path::new("a.xyz")).is_err());
+    }
+
+    #[test]
+    fn exporter_csv() {
+        let mut exporter: Exporter<Vec<u8>> = Exporter::new(Vec::new(), Format::Csv).unwrap();
+        exporter.write(5, 0.5, &mock_output()).unwrap();
+
+        let csv: String = String::from_utf8(exporter.finish().unwrap()).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "step,time,id,x,y,vx,vy,speed,density,stress,species,goal"
+        );
+        assert_eq!(lines[1], "5,0.5,3,1,2,1,0,10,1,0.1,0,");
+        assert_eq!(lines[2], "5,0.5,8,3.5,4.5,0,-1,20,2,0.75,1,0");
+    }
+
+    #[test]
+    fn exporter_json_lines() {
+        let mut exporter: Exporter<Vec<u8>> = Exporter::new(Vec::new(), Format::JsonLines).unwrap();
+        exporter.write(1, 0.25, &mock_output()).unwrap();
+        exporter.write(2, 0.5, &mock_output()).unwrap();
+
+        let json: String = String::from_utf8(exporter.finish().unwrap()).unwrap();
+        let frames: Vec<serde_json::Value> = json
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[1]["step"], 2);
+        assert_eq!(frames[0]["ids"], serde_json::json!([3, 8]));
+    }
+
+    fn take<const N: usize>(bytes: &mut &[u8]) -> [u8; N] {
+        let (head, tail): (&[u8], &[u8]) = bytes.split_at(N);
+        *bytes = tail;
+        head.try_into().unwrap()
+    }
+
+    fn read_binary(mut bytes: &[u8]) -> Vec<(u64, f32, SimulatorOutput)> {
+        assert_eq!(&take::<4>(&mut bytes), b"FSSB");
+        assert_eq!(u32::from_le_bytes(take(&mut bytes)), 2);
+
+        let mut frames: Vec<(u64, f32, SimulatorOutput)> = Vec::new();
+        while !bytes.is_empty() {
+            let step: u64 = u64::from_le_bytes(take(&mut bytes));
+            let time: f32 = f32::from_le_bytes(take(&mut bytes));
+            let mut output: SimulatorOutput = SimulatorOutput::default();
+
+            for _ in 0..u32::from_le_bytes(take(&mut bytes)) {
+                output
+                    .ids
+                    .push(u64::from_le_bytes(take(&mut bytes)) as usize);
+                let [x, y, vx, vy, speed]: [f32; 5] =
+                    [(); 5].map(|_| f32::from_le_bytes(take(&mut bytes)));
+                output.positions.push([x, y]);
+                output.velocities.push([vx, vy]);
+                output.speeds.push(speed);
+                output
+                    .densities
+                    .push(u32::from_le_bytes(take(&mut bytes)) as usize);
+                output.stresses.push(f32::from_le_bytes(take(&mut bytes)));
+                let [species, goal]: [u8; 2] = take(&mut bytes);
+                output.species.push(species as usize);
+                output.goals.push((goal as usize).checked_sub(1));
+            }
+            frames.push((step, time, output));
+        }
+        frames
+    }
+
+    #[test]
+    fn exporter_binary_round_trip() {
+        let mut output: SimulatorOutput = mock_output();
+        output.ids[1] = u32::MAX as usize + 8;
+
+        let mut exporter: Exporter<Vec<u8>> = Exporter::new(Vec::new(), Format::Binary).unwrap();
+        exporter.write(7, 1.0, &output).unwrap();
+        exporter.write(8, 1.5, &mock_output()).unwrap();
+
+        let bytes: Vec<u8> = exporter.finish().unwrap();
+        assert_eq!(bytes.len(), 8 + 2 * (16 + 2 * 38));
+
+        let frames: Vec<(u64, f32, SimulatorOutput)> = read_binary(&bytes);
+        assert_eq!(frames.len(), 2);
+        assert_eq!((frames[0].0, frames[0].1), (7, 1.0));
+        assert_eq!((frames[1].0, frames[1].1), (8, 1.5));
+        for ((_, _, read), written) in frames.iter().zip([&output, &mock_output()]) {
+            assert_eq!(read.ids, written.ids);
+            assert_eq!(read.positions, written.positions);
+            assert_eq!(read.velocities, written.velocities);
+            assert_eq!(read.speeds, written.speeds);
+            assert_eq!(read.densities, written.densities);
+            assert_eq!(read.stresses, written.stresses);
+            assert_eq!(read.species, written.species);
+            assert_eq!(read.goals, written.goals);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use eframe::emath::{Pos2, Rect, Vec2};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Camera {
+    pub center: Pos2,
+    pub zoom: f32,
+    pub follow: bool,
+    viewport: Rect,
+    fitted: Option<[usize; 2]>,
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Self {
+            center: Pos2::ZERO,
+            zoom: 1.0,
+            follow: false,
+            viewport: Rect::NOTHING,
+            fitted: None,
+        }
+    }
+}
+
+impl Camera {
+    pub const MIN_ZOOM: f32 = 0.01;
+    pub const MAX_ZOOM: f32 = 50.0;
+
+    pub fn set_viewport(&mut self, viewport: Rect, width: usize, height: usize) {
+        self.viewport = viewport;
+
+        if self.fitted != Some([width, height]) {
+            self.fit(width, height);
+        }
+    }
+
+    pub fn viewport(&self) -> Rect {
+        self.viewport
+    }
+
+    pub fn world_to_screen(&self, [x, y]: [f32; 2]) -> Pos2 {
+        self.viewport.center() + (Pos2::new(x, y) - self.center) * self.zoom
+    }
+
+    pub fn screen_to_world(&self, pos: Pos2) -> [f32; 2] {
+        let world: Pos2 = self.center + (pos - self.viewport.center()) / self.zoom;
+        [world.x, world.y]
+    }
+
+    pub fn world_rect(&self, width: usize, height: usize) -> Rect {
+        Rect::from_min_max(
+            self.world_to_screen([0.0, 0.0]),
+            self.world_to_screen([width as f32, height as f32]),
+        )
+    }
+
+    pub fn pan(&mut self, delta: Vec2) {
+        self.center -= delta / self.zoom;
+        self.follow = false;
+    }
+
+    pub fn zoom_at(&mut self, anchor: Pos2, factor: f32) {
+        let [x, y]: [f32; 2] = self.screen_to_world(anchor);
+        self.zoom = (self.zoom * factor).clamp(Self::MIN_ZOOM, Self::MAX_ZOOM);
+
+        let [new_x, new_y]: [f32; 2] = self.screen_to_world(anchor);
+        if !self.follow {
+            self.center += Vec2::new(x - new_x, y - new_y);
+        }
+    }
+
+    pub fn fit(&mut self, width: usize, height: usize) {
+        self.center = Pos2::new(width as f32 / 2.0, height as f32 / 2.0);
+        self.follow = false;
+        self.fitted = Some([width, height]);
+
+        if self.viewport.is_positive() && width > 0 && height > 0 {
+            self.zoom = (self.viewport.width() / width as f32)
+                .min(self.viewport.height() / height as f32)
+                .clamp(Self::MIN_ZOOM, Self::MAX_ZOOM);
+        }
+    }
+
+    pub fn is_visible(&self, pos: Pos2, margin: f32) -> bool {
+        self.viewport.expand(margin).contains(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Camera;
+    use eframe::emath::{Pos2, Rect, Vec2};
+
+    fn camera() -> Camera {
+        let mut camera: Camera = Camera::default();
+        camera.set_viewport(
+            Rect::from_min_size(Pos2::new(100.0, 0.0), Vec2::new(800.0, 400.0)),
+            800,
+            400,
+        );
+        camera
+    }
+
+    #[test]
+    fn camera_fits_world() {
+        let mut camera: Camera = camera();
+        assert_eq!(camera.zoom, 1.0);
+        assert_eq!(camera.world_to_screen([0.0, 0.0]), Pos2::new(100.0, 0.0));
+
+        camera.fit(4_000, 1_000);
+        assert_eq!(camera.zoom, 0.2);
+        assert_eq!(
+            camera.world_to_screen([2_000.0, 500.0]),
+            Pos2::new(500.0, 200.0)
+        );
+    }
+
+    #[test]
+    fn camera_transforms_round_trip() {
+        let mut camera: Camera = camera();
+        camera.pan(Vec2::new(30.0, -10.0));
+        camera.zoom_at(Pos2::new(250.0, 120.0), 3.0);
+
+        let screen: Pos2 = camera.world_to_screen([12.5, 340.0]);
+        let [x, y]: [f32; 2] = camera.screen_to_world(screen);
+        assert!((x - 12.5).abs() < 1e-3);
+        assert!((y - 340.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn camera_zoom_keeps_anchor() {
+        let mut camera: Camera = camera();
+        let anchor: Pos2 = Pos2::new(300.0, 100.0);
+        let before: [f32; 2] = camera.screen_to_world(anchor);
+
+        camera.zoom_at(anchor, 4.0);
+        assert_eq!(camera.zoom, 4.0);
+        assert_eq!(camera.screen_to_world(anchor), before);
+
+        camera.zoom_at(anchor, 1e6);
+        assert_eq!(camera.zoom, Camera::MAX_ZOOM);
+    }
+
+    #[test]
+    fn camera_pan_stops_following() {
+        let mut camera: Camera = camera();
+        camera.follow = true;
+
+        camera.pan(Vec2::new(50.0, 0.0));
+        assert!(!camera.follow);
+        assert_eq!(camera.center, Pos2::new(350.0, 200.0));
+    }
+}
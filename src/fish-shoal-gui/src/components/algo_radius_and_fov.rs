@@ -31,6 +31,7 @@ impl AlgoRadiusFov {
         velocity: Vec2,
         painter: &Painter,
         filed: bool,
+        scale: f32,
     ) {
         let base_angle: f32 = velocity.angle();
         let deg_to_rad: f32 = PI / 180.0;
@@ -47,7 +48,7 @@ impl AlgoRadiusFov {
                 let t: f32 = i as f32 / steps as f32;
                 let angle: f32 = base_angle - half_rad + (2.0 * half_rad * t);
                 let (s, c): (f32, f32) = angle.sin_cos();
-                let p: Pos2 = position + Vec2::new(c, s) * radius;
+                let p: Pos2 = position + Vec2::new(c, s) * radius * scale;
                 points.push(p);
             }
 
@@ -77,11 +78,15 @@ impl AlgoRadiusFov {
         let avoidance_color: Color32 = Color32::from_rgba_unmultiplied(255, 0, 0, 120);
 
         if filed {
-            painter.circle_filled(position, app.config.avoidance_radius, avoidance_color);
+            painter.circle_filled(
+                position,
+                app.config.avoidance_radius * scale,
+                avoidance_color,
+            );
         } else {
             painter.circle_stroke(
                 position,
-                app.config.avoidance_radius,
+                app.config.avoidance_radius * scale,
                 Stroke::new(1.0, avoidance_color),
             );
         }
@@ -14,17 +14,21 @@
  * limitations under the License.
  */
 
-use super::AlgoRadiusFov;
-use crate::{FishShoalGui, FocusedFishData};
+use super::{AlgoRadiusFov, Goals};
+use crate::{Camera, ColorMode, FishShoalGui, FocusedFishData};
 use eframe::{
     egui::{Painter, Shape, Stroke},
     emath::{Pos2, Vec2},
     epaint::Color32,
 };
-use fish_shoal_simulator::SimulatorOutput;
+use fish_shoal_simulator::{SimulatorOutput, MAX_SPECIES};
 
 const FISH_LENGTH: f32 = 10.0;
 const FISH_HEAD_RADIUS: f32 = 3.0;
+const PREDATOR_SCALE: f32 = 2.5;
+const PREDATOR_COLOR: Color32 = Color32::from_rgb(200, 30, 30);
+const MIN_SCALE: f32 = 0.3;
+const PICK_RADIUS: f32 = 8.0;
 
 pub struct Entities;
 
@@ -34,20 +38,48 @@ impl Entities {
         primary_pressed: bool,
         painter: Painter,
         data: SimulatorOutput,
-        origin: Pos2,
     ) {
+        let camera: Camera = app.camera;
+        let palette: [Color32; MAX_SPECIES] = Self::palette(app);
+
         for idx in 0..data.ids.len() {
-            Self::render_entity(idx, app, primary_pressed, &painter, &data, origin);
+            Self::render_entity(
+                idx,
+                app,
+                primary_pressed,
+                &painter,
+                &data,
+                &camera,
+                &palette,
+            );
+        }
+
+        for idx in 0..data.predator_ids.len() {
+            Self::render_predator(idx, &painter, &data, &camera);
         }
     }
 
+    fn render_predator(idx: usize, painter: &Painter, data: &SimulatorOutput, camera: &Camera) {
+        let [vx, vy]: [f32; 2] = data.predator_velocities[idx];
+        let scale: f32 = PREDATOR_SCALE * Self::scale(camera);
+
+        let position: Pos2 = camera.world_to_screen(data.predator_positions[idx]);
+        if !camera.is_visible(position, FISH_LENGTH * scale) {
+            return;
+        }
+        let points: Vec<Pos2> = Self::fish(position, Vec2::new(vx, vy), scale);
+
+        painter.add(Shape::convex_polygon(points, PREDATOR_COLOR, Stroke::NONE));
+    }
+
     fn render_entity(
         idx: usize,
         app: &mut FishShoalGui,
         primary_pressed: bool,
         painter: &Painter,
         data: &SimulatorOutput,
-        origin: Pos2,
+        camera: &Camera,
+        palette: &[Color32; MAX_SPECIES],
     ) {
         let id: usize = data.ids[idx];
         let position: [f32; 2] = data.positions[idx];
@@ -60,52 +92,93 @@ impl Entities {
             app.focused_fish_data = Some(FocusedFishData::new(position, velocity, speed));
         }
 
-        let position: Pos2 = origin + Vec2::new(position[0], position[1]);
-        let color: Color32 = Self::density_to_color(density);
+        let world_position: Pos2 = Pos2::new(position[0], position[1]);
+        let position: Pos2 = camera.world_to_screen(position);
+        let scale: f32 = Self::scale(camera);
+        if !is_focused_fish && !camera.is_visible(position, FISH_LENGTH * scale) {
+            return;
+        }
+        let color: Color32 = match app.color_mode {
+            ColorMode::Density => Self::density_to_color(density),
+            ColorMode::Species => palette[data.species[idx]],
+            ColorMode::Goal => Goals::color(data.goals[idx]),
+            ColorMode::Stress => Self::stress_to_color(data.stresses[idx]),
+        };
 
         if speed > 0.1 {
             let velocity: Vec2 = Vec2::new(velocity[0], velocity[1]);
 
-            let points: Vec<Pos2> = Self::fish(position, velocity);
+            let points: Vec<Pos2> = Self::fish(position, velocity, scale);
 
-            if primary_pressed && let Some([mx, my]) = app.config.mouse_pos {
-                if position.distance(Pos2::new(mx, my)) <= FISH_LENGTH {
-                    app.focused_fish_id = Some(id);
-                }
+            if primary_pressed
+                && let Some([mx, my]) = app.config.mouse_pos
+                && world_position.distance(Pos2::new(mx, my))
+                    <= FISH_LENGTH.max(PICK_RADIUS / camera.zoom)
+            {
+                app.focused_fish_id = Some(id);
             }
 
             painter.add(Shape::convex_polygon(points, color, Stroke::NONE));
             if is_focused_fish {
-                AlgoRadiusFov::render(app, position, velocity, painter, false);
+                AlgoRadiusFov::render(app, position, velocity, painter, false, camera.zoom);
             }
         } else {
-            painter.circle_filled(position, 2.0, color);
+            painter.circle_filled(position, 2.0 * scale, color);
         }
     }
 
-    fn fish(position: Pos2, velocity: Vec2) -> Vec<Pos2> {
+    fn palette(app: &FishShoalGui) -> [Color32; MAX_SPECIES] {
+        let species = match &app.replay {
+            Some(replay) => &replay.frame().config.species,
+            None => &app.config.species,
+        };
+
+        species.map(|species| {
+            let [r, g, b]: [u8; 3] = species.color;
+            Color32::from_rgb(r, g, b)
+        })
+    }
+
+    fn scale(camera: &Camera) -> f32 {
+        camera.zoom.max(MIN_SCALE)
+    }
+
+    fn fish(position: Pos2, velocity: Vec2, scale: f32) -> Vec<Pos2> {
         let direction: Vec2 = velocity.normalized();
 
         let right: Vec2 = Vec2::new(direction.y, -direction.x);
 
-        let head_center: Pos2 = position + direction * (FISH_LENGTH * 0.2);
-        let tail_tip: Pos2 = head_center - direction * (FISH_LENGTH * 0.8);
+        let length: f32 = FISH_LENGTH * scale;
+        let head_radius: f32 = FISH_HEAD_RADIUS * scale;
+
+        let head_center: Pos2 = position + direction * (length * 0.2);
+        let tail_tip: Pos2 = head_center - direction * (length * 0.8);
 
         let diag_right: Vec2 = (direction + right).normalized();
         let diag_left: Vec2 = (direction - right).normalized();
 
-        let nose_len: f32 = FISH_HEAD_RADIUS * 1.6;
+        let nose_len: f32 = head_radius * 1.6;
 
         vec![
             tail_tip,
-            head_center + right * FISH_HEAD_RADIUS,
-            head_center + diag_right * FISH_HEAD_RADIUS,
+            head_center + right * head_radius,
+            head_center + diag_right * head_radius,
             head_center + direction * nose_len,
-            head_center + diag_left * FISH_HEAD_RADIUS,
-            head_center - right * FISH_HEAD_RADIUS,
+            head_center + diag_left * head_radius,
+            head_center - right * head_radius,
         ]
     }
 
+    fn stress_to_color(stress: f32) -> Color32 {
+        let s: f32 = stress.clamp(0.0, 1.0);
+
+        Color32::from_rgb(
+            (60.0 + 195.0 * s) as u8,
+            (140.0 - 80.0 * s) as u8,
+            (255.0 - 215.0 * s) as u8,
+        )
+    }
+
     fn density_to_color(density: usize) -> Color32 {
         let d: f32 = density.clamp(0, 6) as f32 / 6.0;
 
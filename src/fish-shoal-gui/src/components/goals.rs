@@ -0,0 +1,93 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{Camera, FishShoalGui};
+use eframe::{
+    egui::{Align2, Context, FontId, Painter, Stroke},
+    emath::{Pos2, Vec2},
+    epaint::Color32,
+};
+use fish_shoal_simulator::{Config, GoalKind, MAX_GOALS};
+
+const GOAL_COLORS: [Color32; MAX_GOALS] = [
+    Color32::from_rgb(255, 90, 90),
+    Color32::from_rgb(255, 220, 60),
+];
+const UNINFORMED_COLOR: Color32 = Color32::from_rgb(110, 110, 120);
+const MARKER_RADIUS: f32 = 8.0;
+
+pub struct Goals;
+
+impl Goals {
+    pub fn color(goal: Option<usize>) -> Color32 {
+        goal.map_or(UNINFORMED_COLOR, |goal| GOAL_COLORS[goal])
+    }
+
+    pub fn render(painter: &Painter, cfg: &Config, camera: &Camera, placing: Option<usize>) {
+        for (index, goal) in cfg.goals.iter().enumerate() {
+            let is_active: bool = cfg.informed_fraction > 0.0 && goal.share > 0.0;
+            if goal.kind != GoalKind::Point || !(is_active || placing == Some(index)) {
+                continue;
+            }
+
+            let center: Pos2 = camera.world_to_screen(goal.point);
+            let stroke: Stroke = Stroke::new(2.0, GOAL_COLORS[index]);
+            painter.circle_stroke(center, MARKER_RADIUS, stroke);
+            painter.line_segment(
+                [
+                    center - Vec2::X * MARKER_RADIUS * 1.5,
+                    center + Vec2::X * MARKER_RADIUS * 1.5,
+                ],
+                stroke,
+            );
+            painter.line_segment(
+                [
+                    center - Vec2::Y * MARKER_RADIUS * 1.5,
+                    center + Vec2::Y * MARKER_RADIUS * 1.5,
+                ],
+                stroke,
+            );
+            painter.text(
+                center + Vec2::splat(MARKER_RADIUS),
+                Align2::LEFT_TOP,
+                format!("Goal {}", index + 1),
+                FontId::proportional(12.0),
+                GOAL_COLORS[index],
+            );
+        }
+    }
+
+    pub fn edit(app: &mut FishShoalGui, ctx: &Context) {
+        let Some(index) = app.goal_tool else {
+            return;
+        };
+
+        let (primary_clicked, secondary_clicked): (bool, bool) = ctx.input(|input| {
+            (
+                input.pointer.primary_clicked(),
+                input.pointer.secondary_clicked(),
+            )
+        });
+
+        if secondary_clicked {
+            app.goal_tool = None;
+        } else if primary_clicked && let Some(pos) = app.config.mouse_pos {
+            let goal = &mut app.config.goals[index];
+            goal.kind = GoalKind::Point;
+            goal.point = pos;
+        }
+    }
+}
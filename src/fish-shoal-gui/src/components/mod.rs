@@ -16,10 +16,18 @@
 
 mod algo_radius_and_fov;
 mod entities;
+mod goals;
+mod obstacles;
+mod plots;
 mod sidebar;
 mod simulation;
+mod timeline;
 
 pub use algo_radius_and_fov::AlgoRadiusFov;
 pub use entities::Entities;
+pub use goals::Goals;
+pub use obstacles::Obstacles;
+pub use plots::Plots;
 pub use sidebar::SideBar;
 pub use simulation::Simulation;
+pub use timeline::Timeline;
@@ -0,0 +1,201 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{Camera, FishShoalGui, ObstacleTool};
+use eframe::{
+    egui::{Context, Painter, PointerButton, Shape, Stroke},
+    emath::{Pos2, Rect, Vec2},
+    epaint::Color32,
+};
+use fish_shoal_simulator::{Command, Obstacle};
+
+const FILL_COLOR: Color32 = Color32::from_rgb(70, 70, 80);
+const STROKE_COLOR: Color32 = Color32::from_rgb(150, 150, 160);
+const DRAFT_COLOR: Color32 = Color32::from_rgb(230, 200, 60);
+const MIN_SIZE: f32 = 3.0;
+
+pub struct Obstacles;
+
+impl Obstacles {
+    pub fn render(painter: &Painter, obstacles: &[Obstacle], camera: &Camera) {
+        let stroke: Stroke = Stroke::new(1.0, STROKE_COLOR);
+
+        for obstacle in obstacles {
+            match obstacle {
+                Obstacle::Circle { center, radius } => {
+                    let center: Pos2 = camera.world_to_screen((*center).into());
+                    painter.circle(center, radius * camera.zoom, FILL_COLOR, stroke);
+                }
+                Obstacle::Rect { min, max } => {
+                    let rect: Rect = Rect::from_min_max(
+                        camera.world_to_screen((*min).into()),
+                        camera.world_to_screen((*max).into()),
+                    );
+                    painter.rect_filled(rect, 0.0, FILL_COLOR);
+                    painter.add(Shape::closed_line(
+                        vec![
+                            rect.left_top(),
+                            rect.right_top(),
+                            rect.right_bottom(),
+                            rect.left_bottom(),
+                        ],
+                        stroke,
+                    ));
+                }
+                Obstacle::Polygon { points } => {
+                    let points: Vec<Pos2> = points
+                        .iter()
+                        .map(|&point| camera.world_to_screen(point.into()))
+                        .collect();
+                    painter.add(Shape::convex_polygon(
+                        points.clone(),
+                        FILL_COLOR,
+                        Stroke::NONE,
+                    ));
+                    painter.add(Shape::closed_line(points, stroke));
+                }
+            }
+        }
+    }
+
+    pub fn edit(app: &mut FishShoalGui, ctx: &Context, painter: &Painter) {
+        if app.obstacle_tool == ObstacleTool::None {
+            app.obstacle_draft.clear();
+            return;
+        }
+
+        let (primary_pressed, primary_released, primary_clicked, double_clicked, secondary_clicked) =
+            ctx.input(|input| {
+                (
+                    input.pointer.primary_pressed(),
+                    input.pointer.primary_released(),
+                    input.pointer.primary_clicked(),
+                    input.pointer.button_double_clicked(PointerButton::Primary),
+                    input.pointer.secondary_clicked(),
+                )
+            });
+
+        let mouse_pos: Option<[f32; 2]> = app.config.mouse_pos;
+
+        if secondary_clicked {
+            if !app.obstacle_draft.is_empty() {
+                app.obstacle_draft.clear();
+            } else if let Some(pos) = mouse_pos {
+                let _ = app.command_sender.send(Command::RemoveObstacleAt(pos));
+            }
+            return;
+        }
+
+        match app.obstacle_tool {
+            ObstacleTool::None => (),
+            ObstacleTool::Circle | ObstacleTool::Rect => {
+                if primary_pressed && let Some(pos) = mouse_pos {
+                    app.obstacle_draft = vec![pos];
+                }
+
+                let (Some(&start), Some(end)) = (app.obstacle_draft.first(), mouse_pos) else {
+                    return;
+                };
+
+                let obstacle: Obstacle = Self::drag_obstacle(app.obstacle_tool, start, end);
+
+                if primary_released {
+                    app.obstacle_draft.clear();
+                    if Self::distance(start, end) >= MIN_SIZE {
+                        let _ = app.command_sender.send(Command::AddObstacle(obstacle));
+                    }
+                } else {
+                    Self::render_draft(painter, &obstacle, &app.camera);
+                }
+            }
+            ObstacleTool::Polygon => {
+                if double_clicked {
+                    if app.obstacle_draft.len() >= 3 {
+                        let points: Vec<[f32; 2]> = app.obstacle_draft.drain(..).collect();
+                        let _ = app
+                            .command_sender
+                            .send(Command::AddObstacle(Obstacle::polygon(
+                                points.into_iter().map(Into::into).collect(),
+                            )));
+                    }
+                    return;
+                }
+
+                if primary_clicked
+                    && let Some(pos) = mouse_pos
+                    && app
+                        .obstacle_draft
+                        .last()
+                        .is_none_or(|&last| Self::distance(last, pos) >= MIN_SIZE)
+                {
+                    app.obstacle_draft.push(pos);
+                }
+
+                let mut points: Vec<Pos2> = app
+                    .obstacle_draft
+                    .iter()
+                    .map(|&point| app.camera.world_to_screen(point))
+                    .collect();
+                if let Some(pos) = mouse_pos {
+                    points.push(app.camera.world_to_screen(pos));
+                }
+                painter.add(Shape::line(points, Stroke::new(1.0, DRAFT_COLOR)));
+            }
+        }
+    }
+
+    fn drag_obstacle(tool: ObstacleTool, start: [f32; 2], end: [f32; 2]) -> Obstacle {
+        if tool == ObstacleTool::Circle {
+            Obstacle::circle(start.into(), Self::distance(start, end))
+        } else {
+            Obstacle::rect(start.into(), end.into())
+        }
+    }
+
+    fn render_draft(painter: &Painter, obstacle: &Obstacle, camera: &Camera) {
+        let stroke: Stroke = Stroke::new(1.0, DRAFT_COLOR);
+
+        match obstacle {
+            Obstacle::Circle { center, radius } => {
+                painter.circle_stroke(
+                    camera.world_to_screen((*center).into()),
+                    radius * camera.zoom,
+                    stroke,
+                );
+            }
+            Obstacle::Rect { min, max } => {
+                let rect: Rect = Rect::from_min_max(
+                    camera.world_to_screen((*min).into()),
+                    camera.world_to_screen((*max).into()),
+                );
+                painter.add(Shape::closed_line(
+                    vec![
+                        rect.left_top(),
+                        rect.right_top(),
+                        rect.right_bottom(),
+                        rect.left_bottom(),
+                    ],
+                    stroke,
+                ));
+            }
+            Obstacle::Polygon { .. } => (),
+        }
+    }
+
+    fn distance([ax, ay]: [f32; 2], [bx, by]: [f32; 2]) -> f32 {
+        Vec2::new(bx - ax, by - ay).length()
+    }
+}
@@ -0,0 +1,151 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{FishShoalGui, Sample};
+use eframe::{
+    egui::{
+        Align2, CollapsingHeader, Context, FontId, Painter, RichText, Sense, Shape, Stroke,
+        TopBottomPanel, Ui,
+    },
+    emath::{Pos2, Rect, Vec2},
+    epaint::{Color32, StrokeKind},
+};
+use std::{
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const CHART_HEIGHT: f32 = 80.0;
+
+type Series = (&'static str, Color32, Option<f32>, fn(&Sample) -> f32);
+
+pub struct Plots;
+
+impl Plots {
+    pub(crate) fn render(app: &mut FishShoalGui, ctx: &Context) {
+        TopBottomPanel::bottom("plots").show(ctx, |ui| {
+            CollapsingHeader::new(RichText::new("Plots").size(14.0))
+                .default_open(false)
+                .show(ui, |ui| {
+                    Self::render_controls(app, ui);
+                    Self::render_charts(app, ui);
+                });
+        });
+    }
+
+    fn render_controls(app: &mut FishShoalGui, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            let label: &str = if app.history.paused {
+                "Resume"
+            } else {
+                "Pause"
+            };
+            if ui.button(label).clicked() {
+                app.history.paused = !app.history.paused;
+            }
+            if ui.button("Clear").clicked() {
+                app.history.clear();
+            }
+            if ui.button("Export CSV").clicked() {
+                let secs: u64 = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|duration| duration.as_secs())
+                    .unwrap_or_default();
+                let path: PathBuf = PathBuf::from(format!("fish-shoal-history-{secs}.csv"));
+
+                app.history.status = match app.history.export(&path) {
+                    Ok(()) => format!("Saved to {}", path.display()),
+                    Err(err) => format!("Export failed: {err}"),
+                };
+            }
+            ui.label(format!("{} samples", app.history.samples.len()));
+            ui.label(&app.history.status);
+        });
+    }
+
+    fn render_charts(app: &FishShoalGui, ui: &mut Ui) {
+        let series: [Series; 4] = [
+            ("Mean speed", Color32::LIGHT_BLUE, None, |s| s.mean_speed),
+            ("Mean density", Color32::LIGHT_GREEN, None, |s| {
+                s.mean_density
+            }),
+            ("In group", Color32::GOLD, Some(1.0), |s| s.group_fraction),
+            ("Mean stress", Color32::LIGHT_RED, Some(1.0), |s| {
+                s.mean_stress
+            }),
+        ];
+
+        let width: f32 = (ui.available_width() - ui.spacing().item_spacing.x * 3.0) / 4.0;
+
+        ui.horizontal(|ui| {
+            for (name, color, fixed_max, value) in series {
+                ui.vertical(|ui| {
+                    let values: Vec<f32> = app.history.samples.iter().map(value).collect();
+                    let last: f32 = values.last().copied().unwrap_or_default();
+                    ui.label(format!("{name}: {last:.2}"));
+
+                    let (rect, _) =
+                        ui.allocate_exact_size(Vec2::new(width, CHART_HEIGHT), Sense::hover());
+                    Self::line_chart(ui.painter(), rect, &values, color, fixed_max);
+                });
+            }
+        });
+    }
+
+    fn line_chart(
+        painter: &Painter,
+        rect: Rect,
+        values: &[f32],
+        color: Color32,
+        fixed_max: Option<f32>,
+    ) {
+        painter.rect_stroke(
+            rect,
+            0.0,
+            Stroke::new(0.5, Color32::GRAY),
+            StrokeKind::Inside,
+        );
+
+        if values.len() < 2 {
+            return;
+        }
+
+        let max: f32 = fixed_max
+            .unwrap_or_else(|| values.iter().copied().fold(0.0, f32::max))
+            .max(f32::EPSILON);
+        let step: f32 = rect.width() / (values.len() - 1) as f32;
+
+        let points: Vec<Pos2> = values
+            .iter()
+            .enumerate()
+            .map(|(idx, &value)| {
+                Pos2::new(
+                    rect.left() + idx as f32 * step,
+                    rect.bottom() - (value / max).clamp(0.0, 1.0) * rect.height(),
+                )
+            })
+            .collect();
+
+        painter.add(Shape::line(points, Stroke::new(1.0, color)));
+        painter.text(
+            rect.left_top() + Vec2::new(2.0, 2.0),
+            Align2::LEFT_TOP,
+            format!("{max:.2}"),
+            FontId::monospace(9.0),
+            Color32::GRAY,
+        );
+    }
+}
@@ -15,13 +15,23 @@
  */
 
 use super::AlgoRadiusFov;
-use crate::FishShoalGui;
+use crate::{ColorMode, FishShoalGui, ObstacleTool};
 use eframe::emath::Rect;
 use eframe::{
-    egui::{Align, Context, Layout, RichText, SidePanel, Slider},
+    egui::{
+        Align, Button, CollapsingHeader, Context, Layout, RichText, SidePanel, Slider, TextEdit, Ui,
+    },
     emath::Vec2,
     Frame,
 };
+use fish_shoal_simulator::{
+    BehaviorModelKind, BoundaryMode, Command, Config, GoalKind, HuntingStrategy, InteractionMode,
+    MouseMode, Snapshot, SpeciesInteraction, SteeringMode, StressMode, TraitDistribution,
+    MAX_GOALS, MAX_SPECIES,
+};
+use std::{ops::RangeInclusive, path::Path};
+
+const MAX_WORLD_SIZE: usize = 20_000;
 
 pub struct SideBar;
 
@@ -47,10 +57,8 @@ impl SideBar {
                 };
                 ui.horizontal(|ui| {
                     ui.label(format!("• Focused fish: {focused_fish_id}"));
-                    if app.focused_fish_id.is_some() {
-                        if ui.button("Stop").clicked() {
-                            app.focused_fish_id = None;
-                        }
+                    if app.focused_fish_id.is_some() && ui.button("Stop").clicked() {
+                        app.focused_fish_id = None;
                     }
                 });
                 if let Some(fish) = &app.focused_fish_data {
@@ -69,6 +77,33 @@ impl SideBar {
                     ui.label("    • speed:    none");
                 }
 
+                ui.separator();
+                ui.heading(RichText::new("Metrics").size(14.0));
+                ui.label(format!("• Polarization:  {:.3}", app.metrics.polarization));
+                ui.label(format!("• Milling:       {:.3}", app.metrics.milling));
+                ui.label(format!(
+                    "• Nearest neighbor: {:.1}",
+                    app.metrics.mean_nearest_neighbor_distance
+                ));
+                ui.label(format!("• Average speed: {:.1}", app.metrics.average_speed));
+                ui.label(format!(
+                    "• Groups: {} (largest {})",
+                    app.metrics.cluster_count, app.metrics.largest_cluster
+                ));
+                if app.metrics.informed_count > 0 {
+                    ui.label(format!("• Informed: {}", app.metrics.informed_count));
+                    for (index, accuracy) in app.metrics.goal_accuracy.iter().enumerate() {
+                        ui.label(format!("    • goal {} accuracy: {accuracy:.3}", index + 1));
+                    }
+                }
+                if app.config.stress_mode == StressMode::Contagion {
+                    ui.label(format!("• Alarmed: {}", app.metrics.alarmed_count));
+                    ui.label(format!(
+                        "• Alarm wave: {} fish over {:.0} ({:.0}/s)",
+                        app.metrics.wave_reach, app.metrics.wave_distance, app.metrics.wave_speed
+                    ));
+                }
+
                 ui.separator();
                 ui.heading(RichText::new("Simulation").size(14.0));
                 ui.horizontal(|ui| {
@@ -80,18 +115,314 @@ impl SideBar {
                     }
                 });
 
+                ui.separator();
+                ui.heading(RichText::new("Configuration file").size(14.0));
+                ui.add(TextEdit::singleline(&mut app.config_path).hint_text("config.toml"));
+                ui.horizontal(|ui| {
+                    if ui.button("Load").clicked() {
+                        Self::load_config(app);
+                    }
+                    if ui.button("Save").clicked() {
+                        app.config_status = match app.config.save(Path::new(&app.config_path)) {
+                            Ok(()) => format!("Saved to {}", app.config_path),
+                            Err(err) => err.to_string(),
+                        };
+                    }
+                });
+                if !app.config_status.is_empty() {
+                    ui.label(RichText::new(&app.config_status).size(11.0));
+                }
+
+                ui.separator();
+                ui.heading(RichText::new("Snapshot").size(14.0));
+                ui.add(
+                    TextEdit::singleline(&mut app.snapshot_path).hint_text("snapshot.json"),
+                );
+                ui.horizontal(|ui| {
+                    if ui.button("Load").clicked() {
+                        Self::load_snapshot(app);
+                    }
+                    if ui.button("Save").clicked() {
+                        app.snapshot_status = match app.command_sender.send(Command::TakeSnapshot)
+                        {
+                            Ok(()) => format!("Saving to {}", app.snapshot_path),
+                            Err(err) => err.to_string(),
+                        };
+                    }
+                });
+                if !app.snapshot_status.is_empty() {
+                    ui.label(RichText::new(&app.snapshot_status).size(11.0));
+                }
+
+                ui.separator();
+                ui.heading(RichText::new("Mouse interaction").size(14.0));
+                ui.horizontal(|ui| {
+                    for (mode, label) in [
+                        (MouseMode::None, "None"),
+                        (MouseMode::Predator, "Predator"),
+                        (MouseMode::Attractor, "Attractor"),
+                    ] {
+                        if ui
+                            .selectable_label(app.config.mouse_mode == mode, label)
+                            .clicked()
+                        {
+                            app.config.mouse_mode = mode;
+                        }
+                    }
+                });
+                ui.add(Slider::new(&mut app.config.mouse_radius, 10.0..=300.0).text("Radius"));
+                CollapsingHeader::new("Advanced")
+                    .id_salt("mouse_advanced")
+                    .default_open(false)
+                    .show(ui, |ui| {
+                        let behavior = &mut app.config.behavior;
+                        ui.add(
+                            Slider::new(&mut behavior.mouse_flee_speed, 0.0..=300.0)
+                                .text("Flee speed"),
+                        );
+                        ui.add(
+                            Slider::new(&mut behavior.mouse_panic_speed, 100.0..=5_000.0)
+                                .logarithmic(true)
+                                .text("Panic mouse speed"),
+                        );
+                        ui.add(
+                            Slider::new(&mut behavior.mouse_feed_speed, 0.0..=300.0)
+                                .text("Feed speed"),
+                        );
+                        ui.add(
+                            Slider::new(&mut behavior.mouse_feed_stress, 0.0..=1.0)
+                                .text("Feed stress"),
+                        );
+                        ui.add(
+                            Slider::new(&mut behavior.mouse_feed_distance, 0.0..=50.0)
+                                .text("Feed distance"),
+                        );
+                    });
+
                 ui.separator();
                 ui.heading(RichText::new("Entities").size(14.0));
-                ui.add(Slider::new(&mut app.config.entity_count, 0..=10_000).text("Count"));
+                ui.label(format!("• Fish: {}", app.species_counts.iter().sum::<usize>()));
+                ui.horizontal(|ui| {
+                    ui.label("Color by");
+                    for (mode, label) in [
+                        (ColorMode::Density, "Density"),
+                        (ColorMode::Species, "Species"),
+                        (ColorMode::Goal, "Goal"),
+                        (ColorMode::Stress, "Stress"),
+                    ] {
+                        if ui.selectable_label(app.color_mode == mode, label).clicked() {
+                            app.color_mode = mode;
+                        }
+                    }
+                });
+                CollapsingHeader::new("Advanced")
+                    .id_salt("entities_advanced")
+                    .default_open(false)
+                    .show(ui, |ui| {
+                        let behavior = &mut app.config.behavior;
+                        Self::range_sliders(
+                            ui,
+                            "Spawn speed",
+                            &mut behavior.spawn_speed_min,
+                            &mut behavior.spawn_speed_max,
+                            0.0..=300.0,
+                            1.0,
+                        );
+                        ui.add(
+                            Slider::new(&mut behavior.spawn_stress, 0.0..=1.0).text("Spawn stress"),
+                        );
+                    });
+
+                ui.separator();
+                ui.heading(RichText::new("Species").size(14.0));
+                for index in 0..MAX_SPECIES {
+                    Self::species(app, ui, index);
+                }
+
+                ui.separator();
+                ui.heading(RichText::new("Navigation").size(14.0));
+                let mut informed_fraction: f32 = app.config.informed_fraction * 100.0;
+                ui.add(
+                    Slider::new(&mut informed_fraction, 0.0..=100.0)
+                        .suffix(" %")
+                        .text("Informed fish"),
+                );
+                app.config.informed_fraction = informed_fraction / 100.0;
+                ui.add(Slider::new(&mut app.config.goal_weight, 0.0..=5.0).text("Goal weight"));
+                for index in 0..MAX_GOALS {
+                    Self::goal(app, ui, index);
+                }
+                if app.goal_tool.is_some() {
+                    ui.label("Click to place the goal, right click to stop.");
+                }
+
+                ui.separator();
+                ui.heading(RichText::new("Stress").size(14.0));
+                ui.horizontal(|ui| {
+                    for (mode, label) in [
+                        (StressMode::Rules, "Rules"),
+                        (StressMode::Contagion, "Contagion"),
+                    ] {
+                        if ui
+                            .selectable_label(app.config.stress_mode == mode, label)
+                            .clicked()
+                        {
+                            app.config.stress_mode = mode;
+                        }
+                    }
+                });
+                if app.config.stress_mode == StressMode::Contagion {
+                    Self::stress(app, ui);
+                }
+
+                ui.separator();
+                ui.heading(RichText::new("Obstacles").size(14.0));
+                ui.horizontal(|ui| {
+                    for (tool, label) in [
+                        (ObstacleTool::None, "None"),
+                        (ObstacleTool::Circle, "Circle"),
+                        (ObstacleTool::Rect, "Rectangle"),
+                        (ObstacleTool::Polygon, "Polygon"),
+                    ] {
+                        if ui
+                            .selectable_label(app.obstacle_tool == tool, label)
+                            .clicked()
+                        {
+                            app.obstacle_tool = tool;
+                        }
+                    }
+                });
+                if app.obstacle_tool == ObstacleTool::Polygon {
+                    ui.label("Click to add vertices, double click to close, right click to cancel or delete.");
+                } else if app.obstacle_tool != ObstacleTool::None {
+                    ui.label("Drag to draw, right click to delete.");
+                }
+                ui.add(
+                    Slider::new(&mut app.config.obstacle_look_ahead, 5.0..=150.0)
+                        .text("Look ahead"),
+                );
+                if ui.button("Clear").clicked() {
+                    let _ = app.command_sender.send(Command::ClearObstacles);
+                }
+
+                ui.separator();
+                ui.heading(RichText::new("Predators").size(14.0));
+                ui.add(Slider::new(&mut app.config.predator_count, 0..=20).text("Count"));
+                ui.horizontal(|ui| {
+                    for (strategy, label) in [
+                        (HuntingStrategy::MostIsolated, "Most isolated"),
+                        (HuntingStrategy::Densest, "Densest"),
+                    ] {
+                        if ui
+                            .selectable_label(app.config.predator_strategy == strategy, label)
+                            .clicked()
+                        {
+                            app.config.predator_strategy = strategy;
+                        }
+                    }
+                });
+                ui.add(Slider::new(&mut app.config.predator_speed, 10.0..=300.0).text("Speed"));
+                ui.add(
+                    Slider::new(&mut app.config.predator_vision_radius, 10.0..=500.0)
+                        .text("Vision radius"),
+                );
+                ui.add(
+                    Slider::new(&mut app.config.predator_capture_radius, 1.0..=30.0)
+                        .text("Capture radius"),
+                );
+                ui.add(
+                    Slider::new(&mut app.config.predator_attack_cooldown, 0.0..=10.0)
+                        .suffix(" s")
+                        .text("Attack cooldown"),
+                );
+                ui.add(
+                    Slider::new(&mut app.config.prey_detection_radius, 10.0..=300.0)
+                        .text("Prey detection radius"),
+                );
+                CollapsingHeader::new("Advanced")
+                    .id_salt("predators_advanced")
+                    .default_open(false)
+                    .show(ui, |ui| {
+                        let behavior = &mut app.config.behavior;
+                        ui.add(
+                            Slider::new(&mut behavior.escape_speed, 0.0..=300.0)
+                                .text("Escape speed"),
+                        );
+                        ui.add(
+                            Slider::new(&mut behavior.escape_stress, 0.0..=1.0)
+                                .text("Escape stress"),
+                        );
+                        ui.add(
+                            Slider::new(&mut behavior.hunger_rate, 0.0..=1.0)
+                                .suffix(" /s")
+                                .text("Hunger rate"),
+                        );
+                        ui.add(
+                            Slider::new(&mut behavior.sated_hunger, 0.0..=1.0)
+                                .text("Sated hunger"),
+                        );
+                        ui.add(
+                            Slider::new(&mut behavior.wander_speed_factor, 0.0..=1.0)
+                                .text("Wander speed factor"),
+                        );
+                        ui.add(
+                            Slider::new(&mut behavior.predator_stress, 0.0..=1.0)
+                                .text("Predator stress"),
+                        );
+                    });
 
                 ui.separator();
                 ui.heading(RichText::new("Area").size(14.0));
                 ui.add(
-                    Slider::new(&mut app.config.width, 100..=app.screen.x as usize).text("Width"),
+                    Slider::new(&mut app.config.width, 100..=MAX_WORLD_SIZE)
+                        .logarithmic(true)
+                        .text("Width"),
                 );
                 ui.add(
-                    Slider::new(&mut app.config.height, 100..=app.screen.y as usize).text("Height"),
+                    Slider::new(&mut app.config.height, 100..=MAX_WORLD_SIZE)
+                        .logarithmic(true)
+                        .text("Height"),
                 );
+                if ui.button("Fit to screen").clicked() {
+                    app.config.width = app.screen.x as usize;
+                    app.config.height = app.screen.y as usize;
+                }
+                ui.horizontal(|ui| {
+                    for (mode, label) in [
+                        (BoundaryMode::Wrap, "Wrap"),
+                        (BoundaryMode::Reflect, "Reflect"),
+                        (BoundaryMode::SoftWall, "Soft wall"),
+                    ] {
+                        if ui
+                            .selectable_label(app.config.boundary_mode == mode, label)
+                            .clicked()
+                        {
+                            app.config.boundary_mode = mode;
+                        }
+                    }
+                });
+                if app.config.boundary_mode == BoundaryMode::SoftWall {
+                    ui.add(
+                        Slider::new(&mut app.config.boundary_margin, 5.0..=200.0)
+                            .text("Wall margin"),
+                    );
+                }
+
+                ui.separator();
+                ui.heading(RichText::new("Camera").size(14.0));
+                ui.label(format!("• Zoom: {:.0} %", app.camera.zoom * 100.0));
+                ui.horizontal(|ui| {
+                    if ui.button("Fit").clicked() {
+                        app.camera.fit(app.config.width, app.config.height);
+                    }
+                    if ui.button("1:1").clicked() {
+                        app.camera.zoom = 1.0;
+                    }
+                    ui.add_enabled_ui(app.focused_fish_id.is_some(), |ui| {
+                        ui.checkbox(&mut app.camera.follow, "Follow focused fish");
+                    });
+                });
+                ui.label("Drag to pan, scroll to zoom.");
 
                 ui.separator();
                 ui.heading(RichText::new("Idle behavior change probability").size(14.0));
@@ -116,6 +447,168 @@ impl SideBar {
                 app.config.direction_change_prob = dir_change_prob / 100.0;
                 app.config.speed_change_prob = speed_change_prob / 100.0;
                 app.config.stress_change_prob = stress_change_proba / 100.0;
+                CollapsingHeader::new("Advanced")
+                    .id_salt("idle_advanced")
+                    .default_open(false)
+                    .show(ui, |ui| {
+                        let behavior = &mut app.config.behavior;
+                        Self::range_sliders(
+                            ui,
+                            "Speed",
+                            &mut behavior.idle_speed_min,
+                            &mut behavior.idle_speed_max,
+                            0.0..=300.0,
+                            1.0,
+                        );
+                        Self::range_sliders(
+                            ui,
+                            "Stress",
+                            &mut behavior.idle_stress_min,
+                            &mut behavior.idle_stress_max,
+                            0.0..=1.0,
+                            0.01,
+                        );
+                    });
+
+                ui.separator();
+                ui.heading(RichText::new("Steering").size(14.0));
+                ui.horizontal(|ui| {
+                    for (mode, label) in [
+                        (SteeringMode::Force, "Force"),
+                        (SteeringMode::Lerp, "Lerp"),
+                    ] {
+                        if ui
+                            .selectable_label(app.config.steering_mode == mode, label)
+                            .clicked()
+                        {
+                            app.config.steering_mode = mode;
+                        }
+                    }
+                });
+                if app.config.steering_mode == SteeringMode::Force {
+                    ui.add(
+                        Slider::new(&mut app.config.max_turn_rate, 10.0..=1_080.0)
+                            .suffix("°/s")
+                            .text("Max turn rate"),
+                    );
+                    ui.add(
+                        Slider::new(&mut app.config.max_acceleration, 0.0..=500.0)
+                            .text("Max acceleration"),
+                    );
+                    ui.add(
+                        Slider::new(&mut app.config.max_deceleration, 0.0..=500.0)
+                            .text("Max deceleration"),
+                    );
+                    ui.add(Slider::new(&mut app.config.drag, 0.0..=5.0).text("Drag"));
+                    ui.add(
+                        Slider::new(&mut app.config.cruise_speed, 0.0..=200.0)
+                            .text("Cruise speed"),
+                    );
+                } else {
+                    CollapsingHeader::new("Advanced")
+                        .id_salt("steering_advanced")
+                        .default_open(false)
+                        .show(ui, |ui| {
+                            let behavior = &mut app.config.behavior;
+                            ui.add(Slider::new(&mut behavior.lerp_rate, 0.0..=20.0).text("Rate"));
+                            ui.add(
+                                Slider::new(&mut behavior.lerp_epsilon, 0.0..=5.0)
+                                    .text("Snap distance"),
+                            );
+                        });
+                }
+
+                ui.separator();
+                ui.heading(RichText::new("Behavior model").size(14.0));
+                ui.horizontal(|ui| {
+                    for (model, label) in [
+                        (BehaviorModelKind::Zonal, "Zonal"),
+                        (BehaviorModelKind::Vicsek, "Vicsek"),
+                        (BehaviorModelKind::Couzin, "Couzin"),
+                        (BehaviorModelKind::Reynolds, "Reynolds"),
+                    ] {
+                        if ui
+                            .selectable_label(app.config.behavior_model == model, label)
+                            .clicked()
+                        {
+                            app.config.behavior_model = model;
+                        }
+                    }
+                });
+                ui.horizontal(|ui| {
+                    for (mode, label) in [
+                        (InteractionMode::Metric, "Metric"),
+                        (InteractionMode::Topological, "Topological"),
+                        (InteractionMode::Voronoi, "Voronoi"),
+                    ] {
+                        if ui
+                            .selectable_label(app.config.interaction_mode == mode, label)
+                            .clicked()
+                        {
+                            app.config.interaction_mode = mode;
+                        }
+                    }
+                });
+                if app.config.interaction_mode == InteractionMode::Topological {
+                    ui.add(
+                        Slider::new(&mut app.config.topological_neighbors, 1..=20)
+                            .text("Nearest neighbors"),
+                    );
+                }
+                CollapsingHeader::new("Advanced")
+                    .id_salt("behavior_advanced")
+                    .default_open(false)
+                    .show(ui, |ui| {
+                        let behavior = &mut app.config.behavior;
+                        for (label, speed, stress) in [
+                            (
+                                "Avoidance",
+                                &mut behavior.avoidance_speed,
+                                &mut behavior.avoidance_stress,
+                            ),
+                            (
+                                "Alignment",
+                                &mut behavior.alignment_speed,
+                                &mut behavior.alignment_stress,
+                            ),
+                            (
+                                "Attraction",
+                                &mut behavior.attraction_speed,
+                                &mut behavior.attraction_stress,
+                            ),
+                            ("Alone", &mut behavior.alone_speed, &mut behavior.alone_stress),
+                        ] {
+                            ui.add(Slider::new(speed, 0.0..=300.0).text(format!("{label} speed")));
+                            ui.add(Slider::new(stress, 0.0..=1.0).text(format!("{label} stress")));
+                        }
+                    });
+                match app.config.behavior_model {
+                    BehaviorModelKind::Zonal => {}
+                    BehaviorModelKind::Vicsek => {
+                        ui.add(Slider::new(&mut app.config.vicsek_noise, 0.0..=1.0).text("Noise"));
+                    }
+                    BehaviorModelKind::Couzin => {
+                        ui.add(
+                            Slider::new(&mut app.config.couzin_blind_angle, 0.0..=360.0)
+                                .suffix("°")
+                                .text("Blind angle"),
+                        );
+                    }
+                    BehaviorModelKind::Reynolds => {
+                        ui.add(
+                            Slider::new(&mut app.config.reynolds_separation_weight, 0.0..=5.0)
+                                .text("Separation weight"),
+                        );
+                        ui.add(
+                            Slider::new(&mut app.config.reynolds_alignment_weight, 0.0..=5.0)
+                                .text("Alignment weight"),
+                        );
+                        ui.add(
+                            Slider::new(&mut app.config.reynolds_cohesion_weight, 0.0..=5.0)
+                                .text("Cohesion weight"),
+                        );
+                    }
+                }
 
                 ui.separator();
                 ui.heading(RichText::new("Shoal behavior radius").size(14.0));
@@ -160,25 +653,215 @@ impl SideBar {
                         app,
                         rect.center(),
                         Vec2::new(0.0, -1.0),
-                        &sub_ui.painter(),
+                        sub_ui.painter(),
                         true,
+                        1.0,
                     );
                 });
 
                 ui.with_layout(Layout::bottom_up(Align::Center), |ui| {
                     ui.add_space(10.0);
 
-                    if !app.is_latest_version {
-                        if let Some(download_link) = &app.latest_version_download_link {
-                            ui.hyperlink_to(
-                                RichText::new(">> download latest <<").size(10.0),
-                                download_link,
-                            );
-                        }
+                    if !app.is_latest_version
+                        && let Some(download_link) = &app.latest_version_download_link
+                    {
+                        ui.hyperlink_to(
+                            RichText::new(">> download latest <<").size(10.0),
+                            download_link,
+                        );
                     }
 
                     ui.label(RichText::new(&app.version_msg).size(10.0));
                 });
             });
     }
+
+    fn range_sliders(
+        ui: &mut Ui,
+        label: &str,
+        min: &mut f32,
+        max: &mut f32,
+        range: RangeInclusive<f32>,
+        gap: f32,
+    ) {
+        let (low, high): (f32, f32) = (*range.start(), *range.end());
+        ui.add(Slider::new(min, low..=high - gap).text(format!("{label} min")));
+        ui.add(Slider::new(max, low + gap..=high).text(format!("{label} max")));
+        *max = max.max(*min + gap);
+    }
+
+    fn species(app: &mut FishShoalGui, ui: &mut Ui, index: usize) {
+        let alive: usize = app.species_counts[index];
+        let species = &mut app.config.species[index];
+
+        ui.horizontal(|ui| {
+            ui.color_edit_button_srgb(&mut species.color);
+            ui.add(
+                Slider::new(&mut species.count, 0..=10_000).text(format!("Species {}", index + 1)),
+            );
+        });
+        CollapsingHeader::new(format!("Species {} ({alive} alive)", index + 1))
+            .id_salt(("species", index))
+            .default_open(false)
+            .show(ui, |ui| {
+                ui.label("Traits, sampled when fish spawn.");
+                let traits = &mut species.traits;
+                Self::trait_sliders(ui, "Body length", &mut traits.body_length, 1.0..=50.0);
+                Self::trait_sliders(ui, "Speed", &mut traits.speed, 0.1..=3.0);
+                Self::trait_sliders(ui, "Perception", &mut traits.perception, 0.1..=3.0);
+                Self::trait_sliders(ui, "Sociability", &mut traits.sociability, 0.01..=1.0);
+                Self::trait_sliders(ui, "Boldness", &mut traits.boldness, 0.01..=1.0);
+
+                ui.label("Reaction to each species.");
+                for (other, interaction) in species.interactions.iter_mut().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.label(format!("{}:", other + 1));
+                        for (rule, label) in [
+                            (SpeciesInteraction::Align, "Align"),
+                            (SpeciesInteraction::Attract, "Attract"),
+                            (SpeciesInteraction::Ignore, "Ignore"),
+                            (SpeciesInteraction::Avoid, "Avoid"),
+                        ] {
+                            if ui.selectable_label(*interaction == rule, label).clicked() {
+                                *interaction = rule;
+                            }
+                        }
+                    });
+                }
+            });
+    }
+
+    fn stress(app: &mut FishShoalGui, ui: &mut Ui) {
+        let stress = &mut app.config.stress;
+
+        ui.add(Slider::new(&mut stress.baseline, 0.0..=0.9).text("Baseline"));
+        ui.add(
+            Slider::new(&mut stress.decay_rate, 0.0..=10.0)
+                .suffix("/s")
+                .text("Decay rate"),
+        );
+        ui.add(
+            Slider::new(&mut stress.alarm_threshold, stress.baseline + 0.05..=1.0)
+                .text("Alarm threshold"),
+        );
+        stress.alarm_threshold = stress.alarm_threshold.max(stress.baseline + 0.05);
+        ui.add(Slider::new(&mut stress.alarm_speed, 0.0..=300.0).text("Alarm speed"));
+        ui.add(Slider::new(&mut stress.alarm_stress, 0.0..=1.0).text("Alarm stress"));
+        let mut transmission_probability: f64 = stress.transmission_probability * 100.0;
+        ui.add(
+            Slider::new(&mut transmission_probability, 0.0..=100.0)
+                .suffix(" %")
+                .text("Transmission"),
+        );
+        stress.transmission_probability = transmission_probability / 100.0;
+        ui.add(
+            Slider::new(&mut stress.transmission_delay, 0.0..=1.0)
+                .suffix(" s")
+                .text("Delay"),
+        );
+        ui.add(Slider::new(&mut stress.transmission_radius, 5.0..=150.0).text("Radius"));
+        ui.add(
+            Slider::new(&mut stress.refractory_period, 0.0..=10.0)
+                .suffix(" s")
+                .text("Refractory period"),
+        );
+
+        let focused: Option<[f32; 2]> = app.focused_fish_data.as_ref().map(|fish| fish.position);
+        if ui
+            .add_enabled(focused.is_some(), Button::new("Startle focused fish"))
+            .clicked()
+            && let Some(position) = focused
+        {
+            let _ = app.command_sender.send(Command::Startle(position));
+        }
+    }
+
+    fn goal(app: &mut FishShoalGui, ui: &mut Ui, index: usize) {
+        let goal = &mut app.config.goals[index];
+
+        ui.add(Slider::new(&mut goal.share, 0.0..=1.0).text(format!("Goal {} share", index + 1)));
+        ui.horizontal(|ui| {
+            for (kind, label) in [
+                (GoalKind::Direction, "Direction"),
+                (GoalKind::Point, "Point"),
+            ] {
+                if ui.selectable_label(goal.kind == kind, label).clicked() {
+                    goal.kind = kind;
+                }
+            }
+            let is_placing: bool = app.goal_tool == Some(index);
+            if ui.selectable_label(is_placing, "Place").clicked() {
+                app.goal_tool = if is_placing { None } else { Some(index) };
+            }
+        });
+        if goal.kind == GoalKind::Direction {
+            ui.add(
+                Slider::new(&mut goal.direction, 0.0..=360.0)
+                    .suffix("°")
+                    .text("Direction"),
+            );
+        } else {
+            ui.label(format!(
+                "    • point: {{{:.0}, {:.0}}}",
+                goal.point[0], goal.point[1]
+            ));
+        }
+    }
+
+    fn trait_sliders(
+        ui: &mut Ui,
+        label: &str,
+        distribution: &mut TraitDistribution,
+        range: RangeInclusive<f32>,
+    ) {
+        let spread: f32 = (*range.end() - *range.start()) / 2.0;
+        ui.add(Slider::new(&mut distribution.mean, range).text(label));
+        ui.add(
+            Slider::new(&mut distribution.std_dev, 0.0..=spread)
+                .prefix("± ")
+                .text(format!("{label} spread")),
+        );
+    }
+
+    fn load_config(app: &mut FishShoalGui) {
+        match Config::load(Path::new(&app.config_path)) {
+            Ok(cfg) => {
+                app.config = Config {
+                    mouse_pos: app.config.mouse_pos,
+                    mouse_vel: app.config.mouse_vel,
+                    mouse_speed: app.config.mouse_speed,
+                    paused: app.config.paused,
+                    ..cfg
+                };
+                app.config_status = format!("Loaded {}", app.config_path);
+            }
+            Err(err) => app.config_status = err.to_string(),
+        }
+    }
+
+    fn load_snapshot(app: &mut FishShoalGui) {
+        let snapshot: Snapshot = match Snapshot::load(Path::new(&app.snapshot_path)) {
+            Ok(snapshot) => snapshot,
+            Err(err) => {
+                app.snapshot_status = err.to_string();
+                return;
+            }
+        };
+
+        app.config = Config {
+            mouse_pos: app.config.mouse_pos,
+            mouse_vel: app.config.mouse_vel,
+            mouse_speed: app.config.mouse_speed,
+            paused: app.config.paused,
+            ..snapshot.config
+        };
+        app.focused_fish_id = None;
+        app.snapshot_status = match app
+            .command_sender
+            .send(Command::Restore(Box::new(snapshot)))
+        {
+            Ok(()) => format!("Loaded {}", app.snapshot_path),
+            Err(err) => err.to_string(),
+        };
+    }
 }
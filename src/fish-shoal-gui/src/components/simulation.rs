@@ -13,15 +13,19 @@
  * See the License for the specific language governing permissions and
  * limitations under the License.
  */
-use crate::{Entities, FishShoalGui};
+use crate::{Entities, FishShoalGui, Goals, ObstacleTool, Obstacles, Timeline};
 use eframe::{
-    egui::{CentralPanel, Context, Painter},
+    egui::{CentralPanel, Context, Painter, PointerButton, Response, Sense, Ui},
     emath::{Pos2, Rect, Vec2},
     epaint::{Color32, Stroke, StrokeKind},
     Frame,
 };
 #[cfg(debug_assertions)]
 use fish_shoal_simulator::SimulatorOutput;
+use std::path::Path;
+
+const ZOOM_SPEED: f32 = 0.002;
+const MIN_GRID_SPACING: f32 = 4.0;
 
 pub struct Simulation;
 
@@ -33,31 +37,93 @@ impl Simulation {
 
             let painter: Painter = ui.painter_at(rect);
 
-            let area: Rect = Self::build_area(app, rect, &painter);
+            Self::update_camera(app, ui, rect);
+            let area: Rect = Self::build_area(app, &painter);
             Self::build_grid(app, area, &painter);
 
             app.old_mouse_pos = app.config.mouse_pos;
-            app.config.mouse_pos = Self::get_mouse_position(ctx, area);
-            let primary_pressed: bool = ctx.input(|input| input.pointer.primary_pressed());
+            app.config.mouse_pos = Self::get_mouse_position(app, ctx, rect.intersect(area));
+            Self::update_mouse_velocity(app, ctx);
+            let primary_pressed: bool = app.obstacle_tool == ObstacleTool::None
+                && app.goal_tool.is_none()
+                && ctx.input(|input| input.pointer.primary_pressed());
 
-            if let Ok(output) = app.data_receiver.recv() {
+            if let Ok(mut output) = app.data_receiver.recv() {
                 #[cfg(debug_assertions)]
                 {
                     Self::check_simulator_output(&output);
                 }
-                Entities::render(app, primary_pressed, painter, output, area.left_top());
+                for (species, captured) in app.config.species.iter_mut().zip(output.captured) {
+                    species.count = species.count.saturating_sub(captured);
+                }
+                if let Some(snapshot) = output.snapshot.take() {
+                    app.snapshot_status = match snapshot.save(Path::new(&app.snapshot_path)) {
+                        Ok(()) => format!("Saved to {}", app.snapshot_path),
+                        Err(err) => err.to_string(),
+                    };
+                }
+                if let Some(replay) = &app.replay {
+                    output = replay.frame().output.clone();
+                } else if !app.config.paused {
+                    app.sim_time += ctx.input(|input| input.stable_dt) as f64;
+                    app.history.record(app.sim_time, &output);
+                    Timeline::record(app, app.sim_time, &output);
+                }
+                app.metrics = output.metrics;
+                app.species_counts = output.species_counts();
+                Obstacles::render(&painter, &output.obstacles, &app.camera);
+                if let Some(replay) = &app.replay {
+                    Goals::render(&painter, &replay.frame().config, &app.camera, None);
+                } else {
+                    Obstacles::edit(app, ctx, &painter);
+                    Goals::edit(app, ctx);
+                    Goals::render(&painter, &app.config, &app.camera, app.goal_tool);
+                }
+                Entities::render(app, primary_pressed, painter, output);
             }
         });
     }
 
-    fn build_area(app: &mut FishShoalGui, rect: Rect, painter: &Painter) -> Rect {
-        let margin_hor: f32 = (app.screen.x - app.config.width as f32) / 2.0;
-        let margin_ver: f32 = (app.screen.y - app.config.height as f32) / 2.0;
+    fn world_size(app: &FishShoalGui) -> (usize, usize) {
+        match &app.replay {
+            Some(replay) => (replay.frame().config.width, replay.frame().config.height),
+            None => (app.config.width, app.config.height),
+        }
+    }
+
+    fn update_camera(app: &mut FishShoalGui, ui: &Ui, rect: Rect) {
+        let (width, height): (usize, usize) = Self::world_size(app);
+        app.camera.set_viewport(rect, width, height);
+
+        let response: Response = ui.interact(rect, ui.id().with("camera"), Sense::drag());
+        if response.dragged_by(PointerButton::Middle)
+            || (app.obstacle_tool == ObstacleTool::None
+                && app.goal_tool.is_none()
+                && response.dragged_by(PointerButton::Primary))
+        {
+            app.camera.pan(response.drag_delta());
+        }
 
-        let area: Rect = Rect::from_min_size(
-            Pos2::new(rect.min.x + margin_hor, rect.min.y + margin_ver),
-            Vec2::new(app.config.width as f32, app.config.height as f32),
-        );
+        if let Some(pointer) = response.hover_pos() {
+            let factor: f32 = ui.input(|input| {
+                input.zoom_delta() * (input.smooth_scroll_delta.y * ZOOM_SPEED).exp()
+            });
+            if factor != 1.0 {
+                app.camera.zoom_at(pointer, factor);
+            }
+        }
+
+        if app.camera.follow
+            && app.focused_fish_id.is_some()
+            && let Some(fish) = &app.focused_fish_data
+        {
+            app.camera.center = Pos2::new(fish.position[0], fish.position[1]);
+        }
+    }
+
+    fn build_area(app: &FishShoalGui, painter: &Painter) -> Rect {
+        let (width, height): (usize, usize) = Self::world_size(app);
+        let area: Rect = app.camera.world_rect(width, height);
 
         painter.rect_stroke(
             area,
@@ -69,32 +135,62 @@ impl Simulation {
         area
     }
 
-    fn build_grid(app: &mut FishShoalGui, area: Rect, painter: &Painter) {
-        let cell_size: f32 = app.config.attraction_radius;
+    fn build_grid(app: &FishShoalGui, area: Rect, painter: &Painter) {
+        let cell_size: f32 = app.config.attraction_radius * app.camera.zoom;
+        if cell_size < MIN_GRID_SPACING {
+            return;
+        }
+
+        let visible: Rect = area.intersect(app.camera.viewport());
         let stroke: Stroke = Stroke::new(0.05, Color32::GRAY);
 
-        let mut x: f32 = area.min.x + cell_size;
-        while x < area.max.x {
-            painter.line_segment([Pos2::new(x, area.min.y), Pos2::new(x, area.max.y)], stroke);
+        let skipped: f32 = ((visible.min.x - area.min.x) / cell_size).floor().max(0.0);
+        let mut x: f32 = area.min.x + (skipped + 1.0) * cell_size;
+        while x < visible.max.x {
+            painter.line_segment(
+                [Pos2::new(x, visible.min.y), Pos2::new(x, visible.max.y)],
+                stroke,
+            );
             x += cell_size;
         }
 
-        let mut y: f32 = area.min.y + cell_size;
-        while y < area.max.y {
-            painter.line_segment([Pos2::new(area.min.x, y), Pos2::new(area.max.x, y)], stroke);
+        let skipped: f32 = ((visible.min.y - area.min.y) / cell_size).floor().max(0.0);
+        let mut y: f32 = area.min.y + (skipped + 1.0) * cell_size;
+        while y < visible.max.y {
+            painter.line_segment(
+                [Pos2::new(visible.min.x, y), Pos2::new(visible.max.x, y)],
+                stroke,
+            );
             y += cell_size;
         }
     }
 
-    fn get_mouse_position(ctx: &Context, area: Rect) -> Option<[f32; 2]> {
-        if let Some(mouse_pos) = ctx.pointer_hover_pos() {
-            if area.contains(mouse_pos) {
-                return Some([mouse_pos.x, mouse_pos.y]);
-            }
+    fn get_mouse_position(app: &FishShoalGui, ctx: &Context, area: Rect) -> Option<[f32; 2]> {
+        if let Some(mouse_pos) = ctx.pointer_hover_pos()
+            && area.contains(mouse_pos)
+        {
+            return Some(app.camera.screen_to_world(mouse_pos));
         }
         None
     }
 
+    fn update_mouse_velocity(app: &mut FishShoalGui, ctx: &Context) {
+        let dt: f32 = ctx.input(|input| input.stable_dt);
+
+        let (Some([ox, oy]), Some([nx, ny])) = (app.old_mouse_pos, app.config.mouse_pos) else {
+            app.config.mouse_vel = None;
+            app.config.mouse_speed = None;
+            return;
+        };
+
+        let delta: Vec2 = Vec2::new(nx - ox, ny - oy);
+        let speed: f32 = if dt > 0.0 { delta.length() / dt } else { 0.0 };
+        let direction: Vec2 = delta.normalized();
+
+        app.config.mouse_vel = Some([direction.x, direction.y]);
+        app.config.mouse_speed = Some(speed);
+    }
+
     #[cfg(debug_assertions)]
     fn check_simulator_output(output: &SimulatorOutput) {
         let count: usize = output.ids.len();
@@ -103,5 +199,9 @@ impl Simulation {
         debug_assert_eq!(output.velocities.len(), count);
         debug_assert_eq!(output.speeds.len(), count);
         debug_assert_eq!(output.densities.len(), count);
+        debug_assert_eq!(output.stresses.len(), count);
+        debug_assert_eq!(output.in_groups.len(), count);
+        debug_assert_eq!(output.species.len(), count);
+        debug_assert_eq!(output.goals.len(), count);
     }
 }
@@ -0,0 +1,168 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{FishShoalGui, Frame, Recorder, Recording, Replay};
+use eframe::egui::{Button, Context, RichText, Slider, TextEdit, TopBottomPanel, Ui};
+use fish_shoal_simulator::SimulatorOutput;
+use std::path::Path;
+
+pub struct Timeline;
+
+impl Timeline {
+    pub(crate) fn render(app: &mut FishShoalGui, ctx: &Context) {
+        let dt: f64 = ctx.input(|input| input.stable_dt) as f64;
+        if let Some(replay) = &mut app.replay {
+            replay.advance(dt);
+        }
+
+        TopBottomPanel::bottom("timeline").show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if app.replay.is_some() {
+                    Self::render_replay(app, ui);
+                } else {
+                    Self::render_live(app, ui);
+                }
+            });
+        });
+    }
+
+    pub(crate) fn record(app: &mut FishShoalGui, time: f64, output: &SimulatorOutput) {
+        let frame: Frame = Frame {
+            time,
+            config: app.config,
+            output: output.clone(),
+        };
+
+        if let Some(recorder) = &mut app.recorder
+            && let Err(err) = recorder.write(&frame)
+        {
+            app.recording_status = format!("Recording failed: {err}");
+            app.recorder = None;
+        }
+
+        app.rewind.push(frame);
+    }
+
+    fn render_live(app: &mut FishShoalGui, ui: &mut Ui) {
+        let label: &str = if app.recorder.is_some() {
+            "Stop recording"
+        } else {
+            "Record"
+        };
+        if ui.button(label).clicked() {
+            Self::toggle_recording(app);
+        }
+        ui.add(
+            TextEdit::singleline(&mut app.recording_path)
+                .hint_text("recording.fssr")
+                .desired_width(200.0),
+        );
+        if ui
+            .add_enabled(app.recorder.is_none(), Button::new("Open"))
+            .clicked()
+        {
+            match Recording::load(Path::new(&app.recording_path)) {
+                Ok(frames) => {
+                    app.recording_status = format!("Loaded {} frames", frames.len());
+                    Self::start_replay(app, frames);
+                }
+                Err(err) => app.recording_status = format!("Open failed: {err}"),
+            }
+        }
+        if ui
+            .add_enabled(
+                !app.rewind.is_empty(),
+                Button::new(format!("Rewind {:.0} s", app.rewind.duration())),
+            )
+            .clicked()
+        {
+            let frames: Vec<Frame> = app.rewind.frames();
+            Self::start_replay(app, frames);
+        }
+        if let Some(recorder) = &app.recorder {
+            ui.label(format!("{} frames recorded", recorder.frames()));
+        } else {
+            ui.label(RichText::new(&app.recording_status).size(11.0));
+        }
+    }
+
+    fn render_replay(app: &mut FishShoalGui, ui: &mut Ui) {
+        let mut stop: bool = false;
+
+        if let Some(replay) = &mut app.replay {
+            if ui.button("Step back").clicked() {
+                replay.step(-1);
+            }
+            let label: &str = if replay.playing { "Pause" } else { "Play" };
+            if ui.button(label).clicked() {
+                replay.toggle();
+            }
+            if ui.button("Step forward").clicked() {
+                replay.step(1);
+            }
+
+            for speed in Replay::SPEEDS {
+                if ui
+                    .selectable_label(replay.speed == speed, format!("{speed}×"))
+                    .clicked()
+                {
+                    replay.speed = speed;
+                }
+            }
+
+            ui.label(format!("{}/{}", replay.index() + 1, replay.len()));
+            ui.spacing_mut().slider_width = (ui.available_width() - 120.0).max(100.0);
+            let duration: f64 = replay.duration();
+            ui.add(Slider::new(&mut replay.cursor, 0.0..=duration).suffix(" s"));
+
+            stop = ui.button("Back to live").clicked();
+        }
+
+        if stop {
+            Self::stop_replay(app);
+        }
+    }
+
+    fn toggle_recording(app: &mut FishShoalGui) {
+        if let Some(recorder) = app.recorder.take() {
+            let frames: usize = recorder.frames();
+            app.recording_status = match recorder.finish() {
+                Ok(_) => format!("Saved {frames} frames to {}", app.recording_path),
+                Err(err) => format!("Recording failed: {err}"),
+            };
+            return;
+        }
+
+        match Recorder::create(Path::new(&app.recording_path)) {
+            Ok(recorder) => app.recorder = Some(recorder),
+            Err(err) => app.recording_status = format!("Recording failed: {err}"),
+        }
+    }
+
+    fn start_replay(app: &mut FishShoalGui, frames: Vec<Frame>) {
+        if let Some(replay) = Replay::new(frames, app.config.paused) {
+            app.config.paused = true;
+            app.obstacle_draft.clear();
+            app.replay = Some(replay);
+        }
+    }
+
+    fn stop_replay(app: &mut FishShoalGui) {
+        if let Some(replay) = app.replay.take() {
+            app.config.paused = replay.resume_paused;
+        }
+    }
+}
@@ -14,24 +14,51 @@
  * limitations under the License.
  */
 
-use crate::{Error, FocusedFishData, FocusedFishId, SideBar, Simulation};
+use crate::{
+    Camera, ColorMode, Error, FocusedFishData, FocusedFishId, History, ObstacleTool, Plots, Recorder, Replay,
+    Rewind, SideBar, Simulation, Timeline,
+};
 use eframe::{
-    egui::{Context, Vec2, ViewportBuilder}, App, CreationContext, Frame,
-    NativeOptions,
+    egui::{Context, Vec2, ViewportBuilder},
+    App, CreationContext, Frame, NativeOptions,
 };
 use egui::ThemePreference;
-use fish_shoal_simulator::{Config, SimulatorOutput};
+use fish_shoal_simulator::{Command, Config, Metrics, SimulatorOutput, MAX_SPECIES};
 use fish_shoal_updater::Updater;
-use std::sync::mpsc::{Receiver, Sender};
+use std::{
+    fs::File,
+    io::BufWriter,
+    sync::mpsc::{Receiver, Sender},
+};
 
 pub struct FishShoalGui {
     pub data_receiver: Receiver<SimulatorOutput>,
     pub config_sender: Sender<Config>,
+    pub command_sender: Sender<Command>,
     pub config: Config,
+    pub valid_config: Config,
+    pub config_path: String,
+    pub config_status: String,
+    pub snapshot_path: String,
+    pub snapshot_status: String,
     pub screen: Vec2,
+    pub camera: Camera,
     pub focused_fish_id: Option<FocusedFishId>,
     pub focused_fish_data: Option<FocusedFishData>,
+    pub metrics: Metrics,
+    pub species_counts: [usize; MAX_SPECIES],
+    pub color_mode: ColorMode,
+    pub history: History,
+    pub sim_time: f64,
     pub old_mouse_pos: Option<[f32; 2]>,
+    pub obstacle_tool: ObstacleTool,
+    pub obstacle_draft: Vec<[f32; 2]>,
+    pub goal_tool: Option<usize>,
+    pub recording_path: String,
+    pub recording_status: String,
+    pub recorder: Option<Recorder<BufWriter<File>>>,
+    pub rewind: Rewind,
+    pub replay: Option<Replay>,
     pub is_latest_version: bool,
     pub version_msg: String,
     pub latest_version_download_link: Option<String>,
@@ -39,15 +66,39 @@ pub struct FishShoalGui {
 }
 
 impl FishShoalGui {
-    pub fn new(data_receiver: Receiver<SimulatorOutput>, config_sender: Sender<Config>) -> Self {
+    pub fn new(
+        data_receiver: Receiver<SimulatorOutput>,
+        config_sender: Sender<Config>,
+        command_sender: Sender<Command>,
+    ) -> Self {
         Self {
             data_receiver,
             config_sender,
+            command_sender,
             config: Config::default(),
+            valid_config: Config::default(),
+            config_path: String::from("fish-shoal.toml"),
+            config_status: String::new(),
+            snapshot_path: String::from("fish-shoal-snapshot.json"),
+            snapshot_status: String::new(),
             screen: Vec2::default(),
+            camera: Camera::default(),
             focused_fish_id: None,
             focused_fish_data: None,
+            metrics: Metrics::default(),
+            species_counts: [0; MAX_SPECIES],
+            color_mode: ColorMode::Density,
+            history: History::default(),
+            sim_time: 0.0,
             old_mouse_pos: None,
+            obstacle_tool: ObstacleTool::None,
+            obstacle_draft: Vec::new(),
+            goal_tool: None,
+            recording_path: String::from("fish-shoal-recording.fssr"),
+            recording_status: String::new(),
+            recorder: None,
+            rewind: Rewind::default(),
+            replay: None,
             is_latest_version: true,
             version_msg: String::new(),
             latest_version_download_link: None,
@@ -77,11 +128,21 @@ impl FishShoalGui {
 
 impl App for FishShoalGui {
     fn update(&mut self, ctx: &Context, frame: &mut Frame) {
+        match self.config.validate() {
+            Ok(()) => self.valid_config = self.config,
+            Err(err) => {
+                self.config_status = err.to_string();
+                self.config = self.valid_config;
+            }
+        }
+
         if self.config_sender.send(self.config).is_err() {
             return;
         }
 
         SideBar::render(self, ctx, frame);
+        Timeline::render(self, ctx);
+        Plots::render(self, ctx);
         Simulation::render(self, ctx, frame);
 
         if !self.initialized {
@@ -0,0 +1,155 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use fish_shoal_simulator::SimulatorOutput;
+use std::{collections::VecDeque, fmt::Write as _, fs, io, path::Path};
+
+const CAPACITY: usize = 1_200;
+const SAMPLE_INTERVAL: f64 = 0.1;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Sample {
+    pub time: f64,
+    pub mean_speed: f32,
+    pub mean_density: f32,
+    pub group_fraction: f32,
+    pub mean_stress: f32,
+}
+
+impl Sample {
+    pub fn from_output(time: f64, output: &SimulatorOutput) -> Self {
+        let count: f32 = output.len().max(1) as f32;
+
+        Self {
+            time,
+            mean_speed: output.speeds.iter().sum::<f32>() / count,
+            mean_density: output.densities.iter().sum::<usize>() as f32 / count,
+            group_fraction: output.in_groups.iter().filter(|&&grouped| grouped).count() as f32
+                / count,
+            mean_stress: output.stresses.iter().sum::<f32>() / count,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct History {
+    pub samples: VecDeque<Sample>,
+    pub paused: bool,
+    pub status: String,
+}
+
+impl History {
+    pub const CSV_HEADER: &'static str = "time,mean_speed,mean_density,group_fraction,mean_stress";
+
+    pub fn record(&mut self, time: f64, output: &SimulatorOutput) {
+        if self.paused {
+            return;
+        }
+
+        if let Some(last) = self.samples.back()
+            && time - last.time < SAMPLE_INTERVAL
+        {
+            return;
+        }
+
+        if self.samples.len() == CAPACITY {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(Sample::from_output(time, output));
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv: String = format!("{}\n", Self::CSV_HEADER);
+
+        for sample in &self.samples {
+            let _ = writeln!(
+                csv,
+                "{:.3},{},{},{},{}",
+                sample.time,
+                sample.mean_speed,
+                sample.mean_density,
+                sample.group_fraction,
+                sample.mean_stress
+            );
+        }
+
+        csv
+    }
+
+    pub fn export(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_csv())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{History, CAPACITY, SAMPLE_INTERVAL};
+    use fish_shoal_simulator::SimulatorOutput;
+
+    fn mock_output() -> SimulatorOutput {
+        SimulatorOutput {
+            ids: vec![0, 1],
+            speeds: vec![10.0, 30.0],
+            densities: vec![1, 4],
+            stresses: vec![0.5, 1.0],
+            in_groups: vec![true, false],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn history_sample_averages() {
+        let mut history: History = History::default();
+        history.record(1.0, &mock_output());
+
+        let sample = history.samples[0];
+        assert_eq!(sample.mean_speed, 20.0);
+        assert_eq!(sample.mean_density, 2.5);
+        assert_eq!(sample.group_fraction, 0.5);
+        assert_eq!(sample.mean_stress, 0.75);
+    }
+
+    #[test]
+    fn history_is_bounded_and_pausable() {
+        let mut history: History = History::default();
+        for i in 0..CAPACITY + 10 {
+            history.record(i as f64 * SAMPLE_INTERVAL * 2.0, &mock_output());
+        }
+        assert_eq!(history.samples.len(), CAPACITY);
+
+        history.paused = true;
+        history.record(1e6, &mock_output());
+        assert_eq!(history.samples.len(), CAPACITY);
+
+        history.clear();
+        assert!(history.samples.is_empty());
+    }
+
+    #[test]
+    fn history_csv() {
+        let mut history: History = History::default();
+        history.record(0.0, &mock_output());
+
+        assert_eq!(
+            history.to_csv(),
+            format!("{}\n0.000,20,2.5,0.5,0.75\n", History::CSV_HEADER)
+        );
+    }
+}
@@ -14,13 +14,25 @@
  * limitations under the License.
  */
 
+mod camera;
+mod color_mode;
 mod components;
 mod error;
 mod focused_fish;
 mod gui;
+mod history;
+mod obstacle_tool;
+mod recording;
+mod replay;
 
 pub use error::Error;
 pub use gui::FishShoalGui;
 
+pub(crate) use camera::Camera;
+pub(crate) use color_mode::ColorMode;
 pub(crate) use components::*;
 pub(crate) use focused_fish::{FocusedFishData, FocusedFishId};
+pub(crate) use history::{History, Sample};
+pub(crate) use obstacle_tool::ObstacleTool;
+pub(crate) use recording::{Frame, Recorder, Recording, Rewind};
+pub(crate) use replay::Replay;
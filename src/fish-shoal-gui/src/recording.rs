@@ -0,0 +1,555 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use fish_shoal_simulator::{
+    Config, ConfigFormat, Metrics, Obstacle, SimulatorOutput, Vec2, MAX_GOALS, MAX_SPECIES,
+};
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{self, BufReader, BufWriter, ErrorKind, Read, Write},
+    path::Path,
+};
+
+const MAGIC: &[u8; 4] = b"FSSR";
+const VERSION: u32 = 4;
+const CONFIG_CHANGED: u8 = 1;
+const OBSTACLES_CHANGED: u8 = 2;
+const REWIND_SECONDS: f64 = 10.0;
+const REWIND_BUDGET: usize = 2_000_000;
+
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub time: f64,
+    pub config: Config,
+    pub output: SimulatorOutput,
+}
+
+impl Frame {
+    fn weight(&self) -> usize {
+        self.output.len() + self.output.predator_ids.len() + 1
+    }
+}
+
+pub struct Recorder<W: Write> {
+    writer: W,
+    config: Option<Config>,
+    obstacles: Option<Vec<Obstacle>>,
+    frames: usize,
+}
+
+impl Recorder<BufWriter<File>> {
+    pub fn create(path: &Path) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<W: Write> Recorder<W> {
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+
+        Ok(Self {
+            writer,
+            config: None,
+            obstacles: None,
+            frames: 0,
+        })
+    }
+
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    pub fn write(&mut self, frame: &Frame) -> io::Result<()> {
+        let output: &SimulatorOutput = &frame.output;
+
+        let config_changed: bool = self.config.as_ref() != Some(&frame.config);
+        let obstacles_changed: bool = self.obstacles.as_ref() != Some(&output.obstacles);
+
+        let mut flags: u8 = 0;
+        if config_changed {
+            flags |= CONFIG_CHANGED;
+        }
+        if obstacles_changed {
+            flags |= OBSTACLES_CHANGED;
+        }
+
+        self.writer.write_all(&frame.time.to_le_bytes())?;
+        self.writer.write_all(&[flags])?;
+
+        if config_changed {
+            let config: String = frame
+                .config
+                .serialize(ConfigFormat::Json)
+                .map_err(|err| io::Error::new(ErrorKind::InvalidInput, err.to_string()))?;
+            self.write_u32(config.len())?;
+            self.writer.write_all(config.as_bytes())?;
+            self.config = Some(frame.config);
+        }
+
+        if obstacles_changed {
+            self.write_u32(output.obstacles.len())?;
+            for obstacle in &output.obstacles {
+                self.write_obstacle(obstacle)?;
+            }
+            self.obstacles = Some(output.obstacles.clone());
+        }
+
+        self.write_u32(output.len())?;
+        for idx in 0..output.len() {
+            let [x, y]: [f32; 2] = output.positions[idx];
+            let [vx, vy]: [f32; 2] = output.velocities[idx];
+
+            self.write_u32(output.ids[idx])?;
+            self.write_f32s(&[x, y, vx, vy, output.speeds[idx], output.stresses[idx]])?;
+            self.write_u32(output.densities[idx])?;
+            let goal: u8 = output.goals[idx].map_or(0, |goal| goal as u8 + 1);
+            self.writer.write_all(&[
+                output.in_groups[idx] as u8,
+                output.species[idx] as u8,
+                goal,
+            ])?;
+        }
+
+        self.write_u32(output.predator_ids.len())?;
+        for idx in 0..output.predator_ids.len() {
+            let [x, y]: [f32; 2] = output.predator_positions[idx];
+            let [vx, vy]: [f32; 2] = output.predator_velocities[idx];
+
+            self.write_u32(output.predator_ids[idx])?;
+            self.write_f32s(&[x, y, vx, vy])?;
+        }
+
+        let metrics: Metrics = output.metrics;
+        self.write_f32s(&[
+            metrics.polarization,
+            metrics.milling,
+            metrics.mean_nearest_neighbor_distance,
+            metrics.average_speed,
+        ])?;
+        self.write_u32(metrics.cluster_count)?;
+        self.write_u32(metrics.largest_cluster)?;
+        self.write_u32(metrics.informed_count)?;
+        self.write_f32s(&metrics.goal_accuracy)?;
+        self.write_u32(metrics.alarmed_count)?;
+        self.write_u32(metrics.wave_reach)?;
+        self.write_f32s(&[metrics.wave_distance, metrics.wave_speed])?;
+
+        self.frames += 1;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    fn write_obstacle(&mut self, obstacle: &Obstacle) -> io::Result<()> {
+        match obstacle {
+            Obstacle::Circle { center, radius } => {
+                self.writer.write_all(&[0])?;
+                self.write_f32s(&[center.x, center.y, *radius])
+            }
+            Obstacle::Rect { min, max } => {
+                self.writer.write_all(&[1])?;
+                self.write_f32s(&[min.x, min.y, max.x, max.y])
+            }
+            Obstacle::Polygon { points } => {
+                self.writer.write_all(&[2])?;
+                self.write_u32(points.len())?;
+                for point in points {
+                    self.write_f32s(&[point.x, point.y])?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn write_u32(&mut self, value: usize) -> io::Result<()> {
+        self.writer.write_all(&(value as u32).to_le_bytes())
+    }
+
+    fn write_f32s(&mut self, values: &[f32]) -> io::Result<()> {
+        for value in values {
+            self.writer.write_all(&value.to_le_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Recording;
+
+impl Recording {
+    pub fn load(path: &Path) -> io::Result<Vec<Frame>> {
+        Self::read(BufReader::new(File::open(path)?))
+    }
+
+    pub fn read<R: Read>(mut reader: R) -> io::Result<Vec<Frame>> {
+        let mut magic: [u8; 4] = [0; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid("not a recording file".to_string()));
+        }
+
+        let version: u32 = read_u32(&mut reader)? as u32;
+        if version != VERSION {
+            return Err(invalid(format!(
+                "recording version {version} is not supported, expected {VERSION}"
+            )));
+        }
+
+        let mut frames: Vec<Frame> = Vec::new();
+        let mut config: Option<Config> = None;
+        let mut obstacles: Vec<Obstacle> = Vec::new();
+
+        loop {
+            let mut time: [u8; 8] = [0; 8];
+            match reader.read_exact(&mut time) {
+                Ok(()) => (),
+                Err(err) if err.kind() == ErrorKind::UnexpectedEof => break,
+                Err(err) => return Err(err),
+            }
+
+            match Self::read_frame(&mut reader, time, &mut config, &mut obstacles) {
+                Ok(frame) => frames.push(frame),
+                Err(err) if err.kind() == ErrorKind::UnexpectedEof => break,
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(frames)
+    }
+
+    fn read_frame<R: Read>(
+        reader: &mut R,
+        time: [u8; 8],
+        config: &mut Option<Config>,
+        obstacles: &mut Vec<Obstacle>,
+    ) -> io::Result<Frame> {
+        let mut flags: [u8; 1] = [0];
+        reader.read_exact(&mut flags)?;
+
+        if flags[0] & CONFIG_CHANGED != 0 {
+            let mut text: Vec<u8> = vec![0; read_u32(reader)?];
+            reader.read_exact(&mut text)?;
+            let text: String = String::from_utf8(text).map_err(|err| invalid(err.to_string()))?;
+            *config = Some(
+                Config::parse(&text, ConfigFormat::Json).map_err(|err| invalid(err.to_string()))?,
+            );
+        }
+
+        if flags[0] & OBSTACLES_CHANGED != 0 {
+            let count: usize = read_u32(reader)?;
+            *obstacles = (0..count)
+                .map(|_| read_obstacle(reader))
+                .collect::<io::Result<_>>()?;
+        }
+
+        let Some(frame_config) = *config else {
+            return Err(invalid("first frame has no configuration".to_string()));
+        };
+
+        let mut output: SimulatorOutput = SimulatorOutput {
+            obstacles: obstacles.clone(),
+            ..Default::default()
+        };
+
+        for _ in 0..read_u32(reader)? {
+            output.ids.push(read_u32(reader)?);
+            let [x, y, vx, vy, speed, stress]: [f32; 6] = read_f32s(reader)?;
+            output.positions.push([x, y]);
+            output.velocities.push([vx, vy]);
+            output.speeds.push(speed);
+            output.stresses.push(stress);
+            output.densities.push(read_u32(reader)?);
+
+            let mut flags: [u8; 3] = [0; 3];
+            reader.read_exact(&mut flags)?;
+            let [in_group, species, goal]: [u8; 3] = flags;
+            output.in_groups.push(in_group != 0);
+            if species as usize >= MAX_SPECIES {
+                return Err(invalid(format!("fish species {species} is out of range")));
+            }
+            output.species.push(species as usize);
+            if goal as usize > MAX_GOALS {
+                return Err(invalid(format!("fish goal {goal} is out of range")));
+            }
+            output.goals.push((goal as usize).checked_sub(1));
+        }
+
+        for _ in 0..read_u32(reader)? {
+            output.predator_ids.push(read_u32(reader)?);
+            let [x, y, vx, vy]: [f32; 4] = read_f32s(reader)?;
+            output.predator_positions.push([x, y]);
+            output.predator_velocities.push([vx, vy]);
+        }
+
+        let [polarization, milling, mean_nearest_neighbor_distance, average_speed]: [f32; 4] =
+            read_f32s(reader)?;
+        output.metrics = Metrics {
+            polarization,
+            milling,
+            mean_nearest_neighbor_distance,
+            average_speed,
+            cluster_count: read_u32(reader)?,
+            largest_cluster: read_u32(reader)?,
+            informed_count: read_u32(reader)?,
+            goal_accuracy: read_f32s(reader)?,
+            alarmed_count: read_u32(reader)?,
+            wave_reach: read_u32(reader)?,
+            ..Metrics::default()
+        };
+        [output.metrics.wave_distance, output.metrics.wave_speed] = read_f32s(reader)?;
+
+        Ok(Frame {
+            time: f64::from_le_bytes(time),
+            config: frame_config,
+            output,
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Rewind {
+    frames: VecDeque<Frame>,
+    weight: usize,
+}
+
+impl Rewind {
+    pub fn push(&mut self, frame: Frame) {
+        self.weight += frame.weight();
+        self.frames.push_back(frame);
+
+        let latest: f64 = self.frames.back().map_or(0.0, |frame| frame.time);
+        while let Some(oldest) = self.frames.front()
+            && self.frames.len() > 1
+            && (latest - oldest.time > REWIND_SECONDS || self.weight > REWIND_BUDGET)
+        {
+            self.weight -= oldest.weight();
+            self.frames.pop_front();
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn duration(&self) -> f64 {
+        match (self.frames.front(), self.frames.back()) {
+            (Some(first), Some(last)) => last.time - first.time,
+            _ => 0.0,
+        }
+    }
+
+    pub fn frames(&self) -> Vec<Frame> {
+        self.frames.iter().cloned().collect()
+    }
+
+    pub fn clear(&mut self) {
+        self.frames.clear();
+        self.weight = 0;
+    }
+}
+
+fn invalid(msg: String) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, msg)
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> io::Result<usize> {
+    let mut bytes: [u8; 4] = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes) as usize)
+}
+
+fn read_f32s<R: Read, const N: usize>(reader: &mut R) -> io::Result<[f32; N]> {
+    let mut values: [f32; N] = [0.0; N];
+    for value in &mut values {
+        let mut bytes: [u8; 4] = [0; 4];
+        reader.read_exact(&mut bytes)?;
+        *value = f32::from_le_bytes(bytes);
+    }
+    Ok(values)
+}
+
+fn read_obstacle<R: Read>(reader: &mut R) -> io::Result<Obstacle> {
+    let mut tag: [u8; 1] = [0];
+    reader.read_exact(&mut tag)?;
+
+    match tag[0] {
+        0 => {
+            let [x, y, radius]: [f32; 3] = read_f32s(reader)?;
+            Ok(Obstacle::circle(Vec2::new(x, y), radius))
+        }
+        1 => {
+            let [min_x, min_y, max_x, max_y]: [f32; 4] = read_f32s(reader)?;
+            Ok(Obstacle::rect(
+                Vec2::new(min_x, min_y),
+                Vec2::new(max_x, max_y),
+            ))
+        }
+        2 => {
+            let count: usize = read_u32(reader)?;
+            let points: Vec<Vec2> = (0..count)
+                .map(|_| read_f32s(reader).map(|[x, y]: [f32; 2]| Vec2::new(x, y)))
+                .collect::<io::Result<_>>()?;
+            Ok(Obstacle::polygon(points))
+        }
+        tag => Err(invalid(format!("unknown obstacle kind {tag}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Frame, Recorder, Recording, Rewind, REWIND_SECONDS};
+    use fish_shoal_simulator::{Config, Metrics, Obstacle, SimulatorOutput, Vec2, MAX_SPECIES};
+
+    fn mock_frame(time: f64, fish_count: usize) -> Frame {
+        let mut config: Config = Config::default();
+        config.species[0].count = fish_count;
+
+        Frame {
+            time,
+            config,
+            output: SimulatorOutput {
+                ids: vec![3, 8],
+                positions: vec![[1.0, 2.0], [3.5, 4.5]],
+                velocities: vec![[1.0, 0.0], [0.0, -1.0]],
+                speeds: vec![10.0, 20.0],
+                densities: vec![1, 2],
+                stresses: vec![0.1, 0.9],
+                in_groups: vec![false, true],
+                species: vec![0, 2],
+                goals: vec![None, Some(1)],
+                predator_ids: vec![12],
+                predator_positions: vec![[7.0, 8.0]],
+                predator_velocities: vec![[0.6, 0.8]],
+                obstacles: vec![
+                    Obstacle::circle(Vec2::new(50.0, 60.0), 10.0),
+                    Obstacle::rect(Vec2::new(0.0, 0.0), Vec2::new(5.0, 5.0)),
+                    Obstacle::polygon(vec![Vec2::ZERO, Vec2::new(4.0, 0.0), Vec2::ONE]),
+                ],
+                metrics: Metrics {
+                    polarization: 0.5,
+                    cluster_count: 2,
+                    informed_count: 1,
+                    goal_accuracy: [0.0, 0.25],
+                    alarmed_count: 1,
+                    wave_reach: 4,
+                    wave_distance: 30.0,
+                    wave_speed: 120.0,
+                    ..Metrics::default()
+                },
+                captured: [0; MAX_SPECIES],
+                snapshot: None,
+            },
+        }
+    }
+
+    fn record(frames: &[Frame]) -> Vec<u8> {
+        let mut recorder: Recorder<Vec<u8>> = Recorder::new(Vec::new()).unwrap();
+        for frame in frames {
+            recorder.write(frame).unwrap();
+        }
+        assert_eq!(recorder.frames(), frames.len());
+        recorder.finish().unwrap()
+    }
+
+    #[test]
+    fn recording_round_trip() {
+        let frames: Vec<Frame> = vec![
+            mock_frame(1.0, 10),
+            mock_frame(1.5, 10),
+            mock_frame(2.0, 20),
+        ];
+        let read: Vec<Frame> = Recording::read(record(&frames).as_slice()).unwrap();
+
+        assert_eq!(read.len(), 3);
+        for (read, frame) in read.iter().zip(&frames) {
+            assert_eq!(read.time, frame.time);
+            assert_eq!(read.config, frame.config);
+            assert_eq!(read.output.ids, frame.output.ids);
+            assert_eq!(read.output.positions, frame.output.positions);
+            assert_eq!(read.output.velocities, frame.output.velocities);
+            assert_eq!(read.output.speeds, frame.output.speeds);
+            assert_eq!(read.output.densities, frame.output.densities);
+            assert_eq!(read.output.stresses, frame.output.stresses);
+            assert_eq!(read.output.in_groups, frame.output.in_groups);
+            assert_eq!(read.output.species, frame.output.species);
+            assert_eq!(read.output.goals, frame.output.goals);
+            assert_eq!(read.output.predator_ids, frame.output.predator_ids);
+            assert_eq!(
+                read.output.predator_positions,
+                frame.output.predator_positions
+            );
+            assert_eq!(read.output.obstacles, frame.output.obstacles);
+            assert_eq!(read.output.metrics, frame.output.metrics);
+        }
+    }
+
+    #[test]
+    fn recording_writes_unchanged_state_once() {
+        let single: usize = record(&[mock_frame(1.0, 10)]).len();
+        let double: usize = record(&[mock_frame(1.0, 10), mock_frame(2.0, 10)]).len();
+        let changed: usize = record(&[mock_frame(1.0, 10), mock_frame(2.0, 20)]).len();
+
+        assert!(double - single < single / 2);
+        assert!(changed > double);
+    }
+
+    #[test]
+    fn recording_keeps_frames_before_truncation() {
+        let frames: Vec<Frame> = vec![
+            mock_frame(1.0, 10),
+            mock_frame(1.5, 10),
+            mock_frame(2.0, 20),
+        ];
+        let bytes: Vec<u8> = record(&frames);
+
+        let read: Vec<Frame> = Recording::read(&bytes[..bytes.len() - 5]).unwrap();
+        assert_eq!(read.len(), 2);
+        assert_eq!(read[1].time, 1.5);
+        assert_eq!(read[1].output.ids, frames[1].output.ids);
+
+        let read: Vec<Frame> = Recording::read(&bytes[..12]).unwrap();
+        assert!(read.is_empty());
+    }
+
+    #[test]
+    fn recording_rejects_other_files() {
+        assert!(Recording::read(b"FSSB\x01\x00\x00\x00".as_slice()).is_err());
+        assert!(Recording::read(b"FSSR\x05\x00\x00\x00".as_slice()).is_err());
+    }
+
+    #[test]
+    fn rewind_keeps_last_seconds() {
+        let mut rewind: Rewind = Rewind::default();
+        for idx in 0..100 {
+            rewind.push(mock_frame(idx as f64 * 0.5, 10));
+        }
+
+        assert_eq!(rewind.duration(), REWIND_SECONDS);
+        assert_eq!(rewind.len(), 21);
+        assert_eq!(rewind.frames()[0].time, 39.5);
+
+        rewind.clear();
+        assert!(rewind.is_empty());
+    }
+}
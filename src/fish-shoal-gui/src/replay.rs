@@ -0,0 +1,158 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::Frame;
+
+#[derive(Debug)]
+pub struct Replay {
+    frames: Vec<Frame>,
+    pub cursor: f64,
+    pub playing: bool,
+    pub speed: f64,
+    pub resume_paused: bool,
+}
+
+impl Replay {
+    pub const SPEEDS: [f64; 6] = [0.1, 0.25, 0.5, 1.0, 2.0, 4.0];
+
+    pub fn new(frames: Vec<Frame>, resume_paused: bool) -> Option<Self> {
+        if frames.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            frames,
+            cursor: 0.0,
+            playing: false,
+            speed: 1.0,
+            resume_paused,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn duration(&self) -> f64 {
+        self.time_of(self.frames.len() - 1)
+    }
+
+    pub fn index(&self) -> usize {
+        let idx: usize = self
+            .frames
+            .partition_point(|frame| frame.time - self.frames[0].time <= self.cursor);
+        idx.saturating_sub(1)
+    }
+
+    pub fn frame(&self) -> &Frame {
+        &self.frames[self.index()]
+    }
+
+    pub fn toggle(&mut self) {
+        if !self.playing && self.cursor >= self.duration() {
+            self.cursor = 0.0;
+        }
+        self.playing = !self.playing;
+    }
+
+    pub fn advance(&mut self, dt: f64) {
+        if !self.playing {
+            return;
+        }
+
+        self.cursor = (self.cursor + dt * self.speed).min(self.duration());
+        if self.cursor >= self.duration() {
+            self.playing = false;
+        }
+    }
+
+    pub fn step(&mut self, delta: isize) {
+        self.playing = false;
+        let idx: usize = self
+            .index()
+            .saturating_add_signed(delta)
+            .min(self.frames.len() - 1);
+        self.cursor = self.time_of(idx);
+    }
+
+    fn time_of(&self, idx: usize) -> f64 {
+        self.frames[idx].time - self.frames[0].time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Replay;
+    use crate::Frame;
+    use fish_shoal_simulator::{Config, SimulatorOutput};
+
+    fn frames(count: usize) -> Vec<Frame> {
+        (0..count)
+            .map(|idx| Frame {
+                time: 5.0 + idx as f64 * 0.5,
+                config: Config::default(),
+                output: SimulatorOutput {
+                    ids: vec![idx],
+                    ..Default::default()
+                },
+            })
+            .collect()
+    }
+
+    #[test]
+    fn replay_requires_frames() {
+        assert!(Replay::new(Vec::new(), false).is_none());
+    }
+
+    #[test]
+    fn replay_plays_at_speed() {
+        let mut replay: Replay = Replay::new(frames(5), false).unwrap();
+        assert_eq!(replay.duration(), 2.0);
+        assert_eq!(replay.frame().output.ids, vec![0]);
+
+        replay.advance(1.0);
+        assert_eq!(replay.index(), 0);
+
+        replay.toggle();
+        replay.speed = 2.0;
+        replay.advance(0.5);
+        assert_eq!(replay.index(), 2);
+
+        replay.advance(10.0);
+        assert_eq!(replay.index(), 4);
+        assert!(!replay.playing);
+
+        replay.toggle();
+        assert_eq!(replay.index(), 0);
+    }
+
+    #[test]
+    fn replay_steps_back_and_forth() {
+        let mut replay: Replay = Replay::new(frames(5), false).unwrap();
+        replay.cursor = 1.2;
+        replay.playing = true;
+
+        replay.step(-1);
+        assert_eq!(replay.index(), 1);
+        assert!(!replay.playing);
+
+        replay.step(-5);
+        assert_eq!(replay.index(), 0);
+
+        replay.step(10);
+        assert_eq!(replay.index(), 4);
+    }
+}
@@ -0,0 +1,82 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    algo::{Couzin, Neighbor, Reynolds, Vicsek, Zonal},
+    Angle, BehaviorModelKind, Boundary, Config, Scalar, StreamRng, Vec2,
+};
+
+#[derive(Debug, Copy, Clone)]
+pub struct Neighborhood<'a> {
+    pub position: Vec2,
+    pub heading: Vec2,
+    pub velocity: Vec2,
+    pub speed: Scalar,
+    pub stress: Scalar,
+    pub perception: f32,
+    pub others: &'a [Neighbor],
+    pub boundary: Boundary,
+}
+
+impl<'a> Neighborhood<'a> {
+    pub fn others(&self) -> impl Iterator<Item = (Vec2, Vec2)> + use<'a> {
+        let position: Vec2 = self.position;
+        let boundary: Boundary = self.boundary;
+
+        self.others
+            .iter()
+            .map(move |other| (boundary.unwrap(position, other.position), other.velocity))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Behavior {
+    pub velocity: Vec2,
+    pub speed: Scalar,
+    pub stress: Scalar,
+}
+
+pub trait BehaviorModel: Sync {
+    fn decide(&self, fish: &Neighborhood, rng: &mut StreamRng) -> Behavior;
+}
+
+impl dyn BehaviorModel {
+    pub fn from_config(cfg: &Config) -> Box<dyn BehaviorModel> {
+        match cfg.behavior_model {
+            BehaviorModelKind::Zonal => Box::new(Zonal::from_config(cfg)),
+            BehaviorModelKind::Vicsek => Box::new(Vicsek::from_config(cfg)),
+            BehaviorModelKind::Couzin => Box::new(Couzin::from_config(cfg)),
+            BehaviorModelKind::Reynolds => Box::new(Reynolds::from_config(cfg)),
+        }
+    }
+}
+
+pub fn is_visible(position: Vec2, heading: Vec2, other_position: Vec2, fov: Angle) -> bool {
+    if fov >= Angle::FULL {
+        return true;
+    }
+
+    let offset: Vec2 = other_position - position;
+    if offset == Vec2::ZERO || heading == Vec2::ZERO {
+        return true;
+    }
+
+    let angle: Angle = (Angle::from_vector(offset) - Angle::from_vector(heading))
+        .normalized()
+        .abs();
+
+    angle <= fov / 2.0
+}
@@ -0,0 +1,152 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    algo::{is_visible, Behavior, BehaviorModel, Neighborhood},
+    Angle, BehaviorParams, Config, Scalar, StreamRng, Vec2,
+};
+
+// See https://doi.org/10.1006/jtbi.2002.3065
+#[derive(Debug, Copy, Clone)]
+pub struct Couzin {
+    repulsion_radius: f32,
+    orientation_radius: f32,
+    attraction_radius: f32,
+    fov: Angle,
+    params: BehaviorParams,
+}
+
+impl Couzin {
+    pub fn from_config(cfg: &Config) -> Self {
+        Self {
+            repulsion_radius: cfg.avoidance_radius,
+            orientation_radius: cfg.alignment_radius,
+            attraction_radius: cfg.attraction_radius,
+            fov: Angle::FULL - Angle::from_degrees(cfg.couzin_blind_angle),
+            params: cfg.behavior,
+        }
+    }
+}
+
+impl BehaviorModel for Couzin {
+    fn decide(&self, fish: &Neighborhood, _rng: &mut StreamRng) -> Behavior {
+        let heading: Vec2 = fish.velocity.normalized();
+
+        let mut repulsion: Vec2 = Vec2::ZERO;
+        let mut orientation: Vec2 = heading;
+        let mut attraction: Vec2 = Vec2::ZERO;
+        let (mut repelled, mut oriented, mut attracted): (bool, bool, bool) = (false, false, false);
+
+        for (other_position, other_velocity) in fish.others() {
+            if !is_visible(fish.position, fish.heading, other_position, self.fov) {
+                continue;
+            }
+
+            let offset: Vec2 = other_position - fish.position;
+            let distance: f32 = offset.length();
+            if distance <= self.repulsion_radius * fish.perception {
+                repulsion -= offset.normalized();
+                repelled = true;
+            } else if distance <= self.orientation_radius * fish.perception {
+                orientation += other_velocity.normalized();
+                oriented = true;
+            } else if distance <= self.attraction_radius * fish.perception {
+                attraction += offset.normalized();
+                attracted = true;
+            }
+        }
+
+        let params: &BehaviorParams = &self.params;
+        let (direction, speed, stress): (Vec2, f32, f32) = if repelled {
+            (repulsion, params.avoidance_speed, params.avoidance_stress)
+        } else {
+            match (oriented, attracted) {
+                (true, true) => (
+                    (orientation.normalized() + attraction.normalized()) * 0.5,
+                    params.alignment_speed,
+                    params.alignment_stress,
+                ),
+                (true, false) => (orientation, params.alignment_speed, params.alignment_stress),
+                (false, true) => (
+                    attraction,
+                    params.attraction_speed,
+                    params.attraction_stress,
+                ),
+                (false, false) => {
+                    return Behavior {
+                        velocity: fish.velocity,
+                        speed: fish.speed,
+                        stress: fish.stress,
+                    };
+                }
+            }
+        };
+
+        let velocity: Vec2 = direction.normalized();
+        Behavior {
+            velocity: if velocity == Vec2::ZERO {
+                fish.velocity
+            } else {
+                velocity
+            },
+            speed: Scalar::new(speed),
+            stress: Scalar::new(stress),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Couzin;
+    use crate::{
+        algo::{self, Behavior},
+        Config, Scalar, Vec2,
+    };
+
+    fn decide(others: &[(Vec2, Vec2)]) -> Behavior {
+        algo::decide(&Couzin::from_config(&Config::default()), others, 0)
+    }
+
+    #[test]
+    fn couzin_repulsion_has_priority() {
+        let behavior: Behavior = decide(&[
+            (Vec2::new(5.0, 0.0), Vec2::new(0.0, 1.0)),
+            (Vec2::new(20.0, 0.0), Vec2::new(0.0, 1.0)),
+        ]);
+
+        assert_eq!(behavior.velocity, Vec2::new(-1.0, 0.0));
+        assert_eq!(behavior.stress, Scalar::new(0.95));
+    }
+
+    #[test]
+    fn couzin_blends_orientation_and_attraction() {
+        let behavior: Behavior = decide(&[
+            (Vec2::new(20.0, 0.0), Vec2::new(1.0, 0.0)),
+            (Vec2::new(0.0, 40.0), Vec2::new(1.0, 0.0)),
+        ]);
+
+        assert!((behavior.velocity.x - 0.5_f32.sqrt()).abs() < 1e-5);
+        assert!((behavior.velocity.y - 0.5_f32.sqrt()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn couzin_ignores_blind_angle() {
+        let behavior: Behavior = decide(&[(Vec2::new(-5.0, 0.0), Vec2::new(0.0, 1.0))]);
+
+        assert_eq!(behavior.velocity, Vec2::new(1.0, 0.0));
+        assert_eq!(behavior.speed, Scalar::new(50.0));
+    }
+}
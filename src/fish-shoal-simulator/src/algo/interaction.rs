@@ -0,0 +1,273 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{algo::Neighbor, Boundary, Config, InteractionMode, Vec2};
+use std::cmp::Ordering;
+
+const NO_EDGE: u32 = u32::MAX;
+
+#[derive(Debug, Default, Clone)]
+pub struct InteractionScratch {
+    cell: Vec<(Vec2, u32)>,
+    clipped: Vec<(Vec2, u32)>,
+    keep: Vec<bool>,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Interaction {
+    mode: InteractionMode,
+    k: usize,
+    boundary: Boundary,
+}
+
+impl Interaction {
+    pub fn from_config(cfg: &Config) -> Self {
+        Self {
+            mode: cfg.interaction_mode,
+            k: cfg.topological_neighbors,
+            boundary: Boundary::from_config(cfg),
+        }
+    }
+
+    pub fn select(
+        &self,
+        position: Vec2,
+        neighbors: &mut Vec<Neighbor>,
+        scratch: &mut InteractionScratch,
+    ) {
+        match self.mode {
+            InteractionMode::Metric => {}
+            InteractionMode::Topological => self.nearest(position, neighbors),
+            InteractionMode::Voronoi => self.voronoi(position, neighbors, scratch),
+        }
+    }
+
+    fn nearest(&self, position: Vec2, neighbors: &mut Vec<Neighbor>) {
+        if neighbors.len() <= self.k {
+            return;
+        }
+
+        let boundary: Boundary = self.boundary;
+        let by_distance = |a: &Neighbor, b: &Neighbor| -> Ordering {
+            let distance_a: f32 = boundary.distance(position, a.position);
+            let distance_b: f32 = boundary.distance(position, b.position);
+            distance_a.total_cmp(&distance_b).then(a.id.cmp(&b.id))
+        };
+
+        neighbors.select_nth_unstable_by(self.k, by_distance);
+        neighbors.truncate(self.k);
+    }
+
+    fn voronoi(
+        &self,
+        position: Vec2,
+        neighbors: &mut Vec<Neighbor>,
+        scratch: &mut InteractionScratch,
+    ) {
+        let extent: f32 = neighbors
+            .iter()
+            .map(|other| self.boundary.distance(position, other.position))
+            .fold(1.0, f32::max);
+
+        scratch.cell.clear();
+        scratch.cell.extend([
+            (Vec2::new(-extent, -extent), NO_EDGE),
+            (Vec2::new(extent, -extent), NO_EDGE),
+            (Vec2::new(extent, extent), NO_EDGE),
+            (Vec2::new(-extent, extent), NO_EDGE),
+        ]);
+
+        for (index, other) in neighbors.iter().enumerate() {
+            let offset: Vec2 = self.boundary.offset(position, other.position);
+            if offset != Vec2::ZERO {
+                scratch.clip(offset, index as u32);
+            }
+        }
+
+        scratch.keep.clear();
+        scratch.keep.resize(neighbors.len(), false);
+        let epsilon: f32 = extent * 1e-4;
+        for (edge, &(start, label)) in scratch.cell.iter().enumerate() {
+            let end: Vec2 = scratch.cell[(edge + 1) % scratch.cell.len()].0;
+            if label != NO_EDGE && start.distance(end) > epsilon {
+                scratch.keep[label as usize] = true;
+            }
+        }
+
+        let mut index: usize = 0;
+        neighbors.retain(|other| {
+            let keep: bool =
+                scratch.keep[index] || self.boundary.offset(position, other.position) == Vec2::ZERO;
+            index += 1;
+            keep
+        });
+    }
+}
+
+impl InteractionScratch {
+    fn clip(&mut self, offset: Vec2, label: u32) {
+        let limit: f32 = offset.length_squared() / 2.0;
+
+        self.clipped.clear();
+        for (edge, &(start, start_label)) in self.cell.iter().enumerate() {
+            let end: Vec2 = self.cell[(edge + 1) % self.cell.len()].0;
+            let start_side: f32 = start.dot(offset) - limit;
+            let end_side: f32 = end.dot(offset) - limit;
+
+            if start_side <= 0.0 {
+                self.clipped.push((start, start_label));
+                if end_side > 0.0 {
+                    let t: f32 = start_side / (start_side - end_side);
+                    self.clipped.push((start.lerp(end, t), label));
+                }
+            } else if end_side <= 0.0 {
+                let t: f32 = start_side / (start_side - end_side);
+                self.clipped.push((start.lerp(end, t), start_label));
+            }
+        }
+
+        std::mem::swap(&mut self.cell, &mut self.clipped);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Interaction, InteractionScratch};
+    use crate::{algo::Neighbor, BoundaryMode, Config, InteractionMode, SimRng, StreamRng, Vec2};
+    use shipyard::EntityId;
+
+    fn neighbors(positions: &[Vec2]) -> Vec<Neighbor> {
+        positions
+            .iter()
+            .enumerate()
+            .map(|(index, &position)| Neighbor {
+                id: EntityId::new_from_index_and_gen(index as u64, 0),
+                position,
+                velocity: Vec2::new(1.0, 0.0),
+            })
+            .collect()
+    }
+
+    fn select(cfg: Config, position: Vec2, positions: &[Vec2]) -> Vec<u64> {
+        let mut selected: Vec<Neighbor> = neighbors(positions);
+        Interaction::from_config(&cfg).select(
+            position,
+            &mut selected,
+            &mut InteractionScratch::default(),
+        );
+
+        let mut ids: Vec<u64> = selected.iter().map(|other| other.id.index()).collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    fn mode(interaction_mode: InteractionMode) -> Config {
+        Config {
+            interaction_mode,
+            boundary_mode: BoundaryMode::Reflect,
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn interaction_metric_keeps_all() {
+        let positions: [Vec2; 3] = [
+            Vec2::new(10.0, 0.0),
+            Vec2::new(20.0, 0.0),
+            Vec2::new(30.0, 0.0),
+        ];
+
+        assert_eq!(
+            select(mode(InteractionMode::Metric), Vec2::ZERO, &positions),
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn interaction_topological_picks_nearest() {
+        let mut rng: StreamRng = SimRng::new(Some(42)).stream(0, SimRng::SPAWN);
+        let origin: Vec2 = Vec2::new(500.0, 500.0);
+        let positions: Vec<Vec2> = (0..50)
+            .map(|_| Vec2::new_random(&mut rng, 450.0..550.0, 450.0..550.0))
+            .collect();
+
+        for k in [1, 6, 20, 50, 80] {
+            let cfg: Config = Config {
+                topological_neighbors: k,
+                ..mode(InteractionMode::Topological)
+            };
+
+            let mut expected: Vec<u64> = (0..positions.len() as u64).collect();
+            expected.sort_by(|&a, &b| {
+                origin
+                    .distance(positions[a as usize])
+                    .total_cmp(&origin.distance(positions[b as usize]))
+            });
+            expected.truncate(k);
+            expected.sort_unstable();
+
+            assert_eq!(select(cfg, origin, &positions), expected, "k = {k}");
+        }
+    }
+
+    #[test]
+    fn interaction_topological_across_wrap_seam() {
+        let cfg: Config = Config {
+            topological_neighbors: 1,
+            boundary_mode: BoundaryMode::Wrap,
+            width: 100,
+            height: 100,
+            ..mode(InteractionMode::Topological)
+        };
+        let positions: [Vec2; 2] = [Vec2::new(20.0, 50.0), Vec2::new(97.0, 50.0)];
+
+        assert_eq!(select(cfg, Vec2::new(2.0, 50.0), &positions), vec![1]);
+    }
+
+    #[test]
+    fn interaction_voronoi_lattice() {
+        let positions: [Vec2; 9] = [
+            Vec2::new(10.0, 0.0),
+            Vec2::new(-10.0, 0.0),
+            Vec2::new(0.0, 10.0),
+            Vec2::new(0.0, -10.0),
+            Vec2::new(10.0, 10.0),
+            Vec2::new(-10.0, 10.0),
+            Vec2::new(10.0, -10.0),
+            Vec2::new(-10.0, -10.0),
+            Vec2::new(20.0, 0.0),
+        ];
+
+        assert_eq!(
+            select(mode(InteractionMode::Voronoi), Vec2::ZERO, &positions),
+            vec![0, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn interaction_voronoi_keeps_distant_sides() {
+        let positions: [Vec2; 3] = [
+            Vec2::new(5.0, 0.0),
+            Vec2::new(15.0, 1.0),
+            Vec2::new(0.0, 45.0),
+        ];
+
+        assert_eq!(
+            select(mode(InteractionMode::Voronoi), Vec2::ZERO, &positions),
+            vec![0, 2]
+        );
+    }
+}
@@ -14,6 +14,53 @@
  * limitations under the License.
  */
 
+mod behavior_model;
+mod couzin;
+mod interaction;
+mod reynolds;
 mod schooling_mechanism;
+mod vicsek;
+mod zonal;
 
-pub use schooling_mechanism::SchoolingMechanism;
+pub use behavior_model::{is_visible, Behavior, BehaviorModel, Neighborhood};
+pub use couzin::Couzin;
+pub use interaction::{Interaction, InteractionScratch};
+pub use reynolds::Reynolds;
+pub use schooling_mechanism::{Neighbor, SchoolingMechanism};
+pub use vicsek::Vicsek;
+pub use zonal::Zonal;
+
+#[cfg(test)]
+pub use fixture::decide;
+
+#[cfg(test)]
+mod fixture {
+    use super::{Behavior, BehaviorModel, Neighbor, Neighborhood};
+    use crate::{Boundary, BoundaryMode, Scalar, SimRng, StreamRng, Vec2};
+    use shipyard::EntityId;
+
+    pub fn decide(model: &dyn BehaviorModel, others: &[(Vec2, Vec2)], key: u64) -> Behavior {
+        let others: Vec<Neighbor> = others
+            .iter()
+            .enumerate()
+            .map(|(index, &(position, velocity))| Neighbor {
+                id: EntityId::new_from_index_and_gen(index as u64 + 1, 0),
+                position,
+                velocity,
+            })
+            .collect();
+        let fish: Neighborhood = Neighborhood {
+            position: Vec2::ZERO,
+            heading: Vec2::new(1.0, 0.0),
+            velocity: Vec2::new(1.0, 0.0),
+            speed: Scalar::new(50.0),
+            stress: Scalar::new(0.1),
+            perception: 1.0,
+            others: &others,
+            boundary: Boundary::new(BoundaryMode::Reflect, 1_000.0, 1_000.0),
+        };
+        let mut rng: StreamRng = SimRng::new(Some(0)).stream(key, SimRng::SWARMING);
+
+        model.decide(&fish, &mut rng)
+    }
+}
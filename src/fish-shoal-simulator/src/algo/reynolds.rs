@@ -0,0 +1,159 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    algo::{is_visible, Behavior, BehaviorModel, Neighborhood},
+    Angle, BehaviorParams, Config, Scalar, StreamRng, Vec2,
+};
+
+// See https://doi.org/10.1145/37402.37406
+#[derive(Debug, Copy, Clone)]
+pub struct Reynolds {
+    separation_radius: f32,
+    alignment_radius: f32,
+    cohesion_radius: f32,
+    alignment_fov: Angle,
+    cohesion_fov: Angle,
+    separation_weight: f32,
+    alignment_weight: f32,
+    cohesion_weight: f32,
+    params: BehaviorParams,
+}
+
+impl Reynolds {
+    pub fn from_config(cfg: &Config) -> Self {
+        Self {
+            separation_radius: cfg.avoidance_radius,
+            alignment_radius: cfg.alignment_radius,
+            cohesion_radius: cfg.attraction_radius,
+            alignment_fov: Angle::from_degrees(cfg.alignment_fov),
+            cohesion_fov: Angle::from_degrees(cfg.attraction_fov),
+            separation_weight: cfg.reynolds_separation_weight,
+            alignment_weight: cfg.reynolds_alignment_weight,
+            cohesion_weight: cfg.reynolds_cohesion_weight,
+            params: cfg.behavior,
+        }
+    }
+}
+
+impl BehaviorModel for Reynolds {
+    fn decide(&self, fish: &Neighborhood, _rng: &mut StreamRng) -> Behavior {
+        let heading: Vec2 = fish.velocity.normalized();
+
+        let mut separation: Vec2 = Vec2::ZERO;
+        let mut alignment: Vec2 = Vec2::ZERO;
+        let mut center: Vec2 = Vec2::ZERO;
+        let mut cohesion_count: f32 = 0.0;
+
+        for (other_position, other_velocity) in fish.others() {
+            let offset: Vec2 = fish.position - other_position;
+            let distance: f32 = offset.length();
+
+            if distance > 0.0 && distance <= self.separation_radius * fish.perception {
+                separation += offset / (distance * distance);
+            }
+            if distance <= self.alignment_radius * fish.perception
+                && is_visible(
+                    fish.position,
+                    fish.heading,
+                    other_position,
+                    self.alignment_fov,
+                )
+            {
+                alignment += other_velocity.normalized();
+            }
+            if distance <= self.cohesion_radius * fish.perception
+                && is_visible(
+                    fish.position,
+                    fish.heading,
+                    other_position,
+                    self.cohesion_fov,
+                )
+            {
+                center += other_position;
+                cohesion_count += 1.0;
+            }
+        }
+
+        let mut steering: Vec2 = separation.normalized() * self.separation_weight
+            + alignment.normalized() * self.alignment_weight;
+        if cohesion_count > 0.0 {
+            steering +=
+                (center / cohesion_count - fish.position).normalized() * self.cohesion_weight;
+        }
+
+        let velocity: Vec2 = (heading + steering).normalized();
+        let (speed, stress): (f32, f32) = if separation != Vec2::ZERO {
+            (self.params.avoidance_speed, self.params.avoidance_stress)
+        } else {
+            (self.params.alignment_speed, self.params.alignment_stress)
+        };
+
+        Behavior {
+            velocity: if velocity == Vec2::ZERO {
+                fish.velocity
+            } else {
+                velocity
+            },
+            speed: Scalar::new(speed),
+            stress: Scalar::new(stress),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Reynolds;
+    use crate::{
+        algo::{self, Behavior},
+        Config, Scalar, Vec2,
+    };
+
+    fn decide(cfg: Config, neighbor_position: Vec2, neighbor_velocity: Vec2) -> Behavior {
+        algo::decide(
+            &Reynolds::from_config(&cfg),
+            &[(neighbor_position, neighbor_velocity)],
+            0,
+        )
+    }
+
+    #[test]
+    fn reynolds_separation_pushes_away() {
+        let behavior: Behavior =
+            decide(Config::default(), Vec2::new(5.0, 5.0), Vec2::new(1.0, 0.0));
+
+        assert!(behavior.velocity.y < 0.0);
+        assert_eq!(behavior.stress, Scalar::new(0.95));
+    }
+
+    #[test]
+    fn reynolds_weights_scale_rules() {
+        let cfg: Config = Config {
+            reynolds_alignment_weight: 0.0,
+            reynolds_cohesion_weight: 0.0,
+            ..Config::default()
+        };
+        let behavior: Behavior = decide(cfg, Vec2::new(20.0, 20.0), Vec2::new(0.0, 1.0));
+        assert_eq!(behavior.velocity, Vec2::new(1.0, 0.0));
+
+        let behavior: Behavior = decide(
+            Config::default(),
+            Vec2::new(20.0, 20.0),
+            Vec2::new(0.0, 1.0),
+        );
+        assert!(behavior.velocity.y > 0.0);
+    }
+}
@@ -14,54 +14,76 @@
 * limitations under the License.
 */
 
-use crate::{Scalar, Vec2};
+use crate::{algo::is_visible, Angle, BehaviorParams, Boundary, Scalar, Vec2};
 use shipyard::EntityId;
-use std::collections::HashMap;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Neighbor {
+    pub id: EntityId,
+    pub position: Vec2,
+    pub velocity: Vec2,
+}
 
 // See ./docs/schooling_mechanism_in_fish.pdf
 #[derive(Debug)]
-pub struct SchoolingMechanism {
+pub struct SchoolingMechanism<'a> {
     position: Vec2,
+    heading: Vec2,
     velocity: Vec2,
     speed: Scalar,
     stress: Scalar,
-    others_positions: HashMap<EntityId, Vec2>,
-    others_velocities: HashMap<EntityId, Vec2>,
-    // others_speeds: HashMap<EntityId, Scalar>,
+    // Sorted by `id`, the averages below depend on the visiting order.
+    others: &'a [Neighbor],
+    boundary: Boundary,
     avoidance_radius: f32,
     alignment_radius: f32,
     attraction_radius: f32,
+    alignment_fov: Angle,
+    attraction_fov: Angle,
+    params: BehaviorParams,
 }
 
-impl SchoolingMechanism {
-    pub const MAX_NEIGHBORS: usize = 6;
-
+impl<'a> SchoolingMechanism<'a> {
     pub fn setup(
         position: Vec2,
         velocity: Vec2,
         speed: Scalar,
         stress: Scalar,
-        others_positions: HashMap<EntityId, Vec2>,
-        others_velocities: HashMap<EntityId, Vec2>,
-        // others_speeds: HashMap<EntityId, Scalar>,
+        others: &'a [Neighbor],
         avoidance_radius: f32,
         alignment_radius: f32,
         attraction_radius: f32,
+        alignment_fov: Angle,
+        attraction_fov: Angle,
+        boundary: Boundary,
     ) -> Self {
         Self {
             position,
+            heading: velocity,
             velocity,
             speed,
             stress,
-            others_positions,
-            others_velocities,
-            // others_speeds,
+            others,
+            boundary,
             avoidance_radius,
             alignment_radius,
             attraction_radius,
+            alignment_fov,
+            attraction_fov,
+            params: BehaviorParams::default(),
         }
     }
 
+    pub fn with_params(mut self, params: BehaviorParams) -> Self {
+        self.params = params;
+        self
+    }
+
+    pub fn with_heading(mut self, heading: Vec2) -> Self {
+        self.heading = heading;
+        self
+    }
+
     pub fn set_behavior(&self, velocity: &mut Vec2, speed: &mut Scalar, stress: &mut Scalar) {
         *velocity = self.velocity;
         *speed = self.speed;
@@ -72,21 +94,18 @@ impl SchoolingMechanism {
         let mut position_to_avoid: Vec2 = Vec2::ZERO;
 
         let mut count: f32 = 0.0;
-        for (_, &other_position) in &self.others_positions {
+        for (other_position, _) in self.others() {
             if self.position.distance(other_position) <= self.avoidance_radius {
                 position_to_avoid += other_position;
                 count += 1.0;
                 position_to_avoid /= count;
-                if count as usize >= Self::MAX_NEIGHBORS {
-                    break;
-                }
             }
         }
 
         if position_to_avoid != Vec2::ZERO {
             self.velocity = (self.position - position_to_avoid).normalized();
-            self.stress.value = 0.95;
-            self.speed.value = 100.0;
+            self.stress.value = self.params.avoidance_stress;
+            self.speed.value = self.params.avoidance_speed;
             true
         } else {
             false
@@ -97,25 +116,28 @@ impl SchoolingMechanism {
         let mut velocity_to_align: Vec2 = Vec2::ZERO;
 
         let mut count: f32 = 0.0;
-        for (other_id, &other_position) in &self.others_positions {
+        for (other_position, other_velocity) in self.others() {
             if self.position.distance(other_position) <= self.avoidance_radius {
                 continue;
             }
-            if self.position.distance(other_position) <= self.alignment_radius {
-                let other_velocity: Vec2 = self.others_velocities[other_id];
+            if self.position.distance(other_position) <= self.alignment_radius
+                && is_visible(
+                    self.position,
+                    self.heading,
+                    other_position,
+                    self.alignment_fov,
+                )
+            {
                 velocity_to_align += other_velocity;
                 count += 1.0;
                 velocity_to_align /= count;
-                if count as usize >= Self::MAX_NEIGHBORS {
-                    break;
-                }
             }
         }
 
         if velocity_to_align != Vec2::ZERO {
             self.velocity = velocity_to_align.normalized();
-            self.stress.value = 0.33;
-            self.speed.value = 75.0;
+            self.stress.value = self.params.alignment_stress;
+            self.speed.value = self.params.alignment_speed;
             true
         } else {
             false
@@ -126,29 +148,148 @@ impl SchoolingMechanism {
         let mut position_to_join: Vec2 = Vec2::ZERO;
 
         let mut count: f32 = 0.0;
-        for (_, &other_position) in &self.others_positions {
+        for (other_position, _) in self.others() {
             let avoid: bool = self.position.distance(other_position) <= self.avoidance_radius;
             let align: bool = self.position.distance(other_position) <= self.alignment_radius;
             if avoid || align {
                 continue;
             }
-            if self.position.distance(other_position) <= self.attraction_radius {
+            if self.position.distance(other_position) <= self.attraction_radius
+                && is_visible(
+                    self.position,
+                    self.heading,
+                    other_position,
+                    self.attraction_fov,
+                )
+            {
                 position_to_join += other_position;
                 count += 1.0;
                 position_to_join /= count;
-                if count as usize >= Self::MAX_NEIGHBORS {
-                    break;
-                }
             }
         }
 
         if position_to_join != Vec2::ZERO {
             self.velocity = (position_to_join - self.position).normalized();
-            self.stress.value = 0.5;
-            self.speed.value = 100.0;
+            self.stress.value = self.params.attraction_stress;
+            self.speed.value = self.params.attraction_speed;
             true
         } else {
             false
         }
     }
+
+    fn others(&self) -> impl Iterator<Item = (Vec2, Vec2)> + use<'a> {
+        let position: Vec2 = self.position;
+        let boundary: Boundary = self.boundary;
+
+        self.others
+            .iter()
+            .map(move |other| (boundary.unwrap(position, other.position), other.velocity))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Neighbor, SchoolingMechanism};
+    use crate::{Angle, Boundary, BoundaryMode, Scalar, Vec2};
+    use shipyard::EntityId;
+
+    fn mock_neighbor(position: Vec2, velocity: Vec2) -> [Neighbor; 1] {
+        [Neighbor {
+            id: EntityId::new_from_index_and_gen(1, 0),
+            position,
+            velocity,
+        }]
+    }
+
+    fn mock_mechanism(others: &[Neighbor], fov_degrees: f32) -> SchoolingMechanism<'_> {
+        SchoolingMechanism::setup(
+            Vec2::ZERO,
+            Vec2::new(1.0, 0.0),
+            Scalar::new(50.0),
+            Scalar::new(0.1),
+            others,
+            10.0,
+            30.0,
+            50.0,
+            Angle::from_degrees(fov_degrees),
+            Angle::from_degrees(fov_degrees),
+            Boundary::new(BoundaryMode::Reflect, 1_000.0, 1_000.0),
+        )
+    }
+
+    #[test]
+    fn schooling_alignment_in_fov() {
+        let others: [Neighbor; 1] = mock_neighbor(Vec2::new(20.0, 5.0), Vec2::new(0.0, 1.0));
+        let mut algo: SchoolingMechanism = mock_mechanism(&others, 180.0);
+
+        assert!(algo.alignment());
+        assert_eq!(algo.velocity, Vec2::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn schooling_alignment_out_of_fov() {
+        let others: [Neighbor; 1] = mock_neighbor(Vec2::new(-20.0, 0.0), Vec2::new(0.0, 1.0));
+        let mut algo: SchoolingMechanism = mock_mechanism(&others, 180.0);
+
+        assert!(!algo.alignment());
+        assert_eq!(algo.velocity, Vec2::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn schooling_attraction_in_fov() {
+        let others: [Neighbor; 1] = mock_neighbor(Vec2::new(0.0, 40.0), Vec2::new(1.0, 0.0));
+        let mut algo: SchoolingMechanism = mock_mechanism(&others, 270.0);
+
+        assert!(algo.attraction());
+        assert_eq!(algo.velocity, Vec2::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn schooling_attraction_in_blind_spot() {
+        let others: [Neighbor; 1] = mock_neighbor(Vec2::new(-40.0, 1.0), Vec2::new(1.0, 0.0));
+        let mut algo: SchoolingMechanism = mock_mechanism(&others, 270.0);
+
+        assert!(!algo.attraction());
+    }
+
+    #[test]
+    fn schooling_full_fov_sees_behind() {
+        let others: [Neighbor; 1] = mock_neighbor(Vec2::new(-40.0, 0.0), Vec2::new(1.0, 0.0));
+        let mut algo: SchoolingMechanism = mock_mechanism(&others, 360.0);
+
+        assert!(algo.attraction());
+        assert_eq!(algo.velocity, Vec2::new(-1.0, 0.0));
+    }
+
+    #[test]
+    fn schooling_avoidance_ignores_fov() {
+        let others: [Neighbor; 1] = mock_neighbor(Vec2::new(-5.0, 0.0), Vec2::new(1.0, 0.0));
+        let mut algo: SchoolingMechanism = mock_mechanism(&others, 0.0);
+
+        assert!(algo.avoidance());
+        assert_eq!(algo.velocity, Vec2::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn schooling_avoidance_across_wrap_seam() {
+        let others: [Neighbor; 1] = mock_neighbor(Vec2::new(99.0, 50.0), Vec2::new(0.0, 1.0));
+
+        let mut algo: SchoolingMechanism = SchoolingMechanism::setup(
+            Vec2::new(1.0, 50.0),
+            Vec2::new(0.0, 1.0),
+            Scalar::new(50.0),
+            Scalar::new(0.1),
+            &others,
+            10.0,
+            30.0,
+            50.0,
+            Angle::from_degrees(360.0),
+            Angle::from_degrees(360.0),
+            Boundary::new(BoundaryMode::Wrap, 100.0, 100.0),
+        );
+
+        assert!(algo.avoidance());
+        assert!(algo.velocity.x > 0.0);
+    }
 }
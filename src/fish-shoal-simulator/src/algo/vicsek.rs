@@ -0,0 +1,109 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    algo::{Behavior, BehaviorModel, Neighborhood},
+    Angle, BehaviorParams, Config, Scalar, StreamRng, Vec2,
+};
+use std::f32::consts::PI;
+
+// See https://doi.org/10.1103/PhysRevLett.75.1226
+#[derive(Debug, Copy, Clone)]
+pub struct Vicsek {
+    radius: f32,
+    noise: f32,
+    params: BehaviorParams,
+}
+
+impl Vicsek {
+    pub fn from_config(cfg: &Config) -> Self {
+        Self {
+            radius: cfg.alignment_radius,
+            noise: cfg.vicsek_noise,
+            params: cfg.behavior,
+        }
+    }
+}
+
+impl BehaviorModel for Vicsek {
+    fn decide(&self, fish: &Neighborhood, rng: &mut StreamRng) -> Behavior {
+        let mut heading: Vec2 = fish.velocity.normalized();
+        for (other_position, other_velocity) in fish.others() {
+            if fish.position.distance(other_position) <= self.radius * fish.perception {
+                heading += other_velocity.normalized();
+            }
+        }
+
+        if heading == Vec2::ZERO {
+            heading = fish.velocity;
+        }
+
+        let mut angle: Angle = Angle::from_vector(heading);
+        if self.noise > 0.0 {
+            let amplitude: f32 = self.noise * PI;
+            angle += Angle::new_random(rng, -amplitude..amplitude);
+        }
+
+        Behavior {
+            velocity: angle.to_vector(),
+            speed: Scalar::new(self.params.alignment_speed),
+            stress: Scalar::new(self.params.alignment_stress),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Vicsek;
+    use crate::{
+        algo::{self, Behavior},
+        Config, Vec2,
+    };
+
+    fn decide(noise: f32, others: &[(Vec2, Vec2)], key: u64) -> Behavior {
+        let cfg: Config = Config {
+            vicsek_noise: noise,
+            ..Config::default()
+        };
+
+        algo::decide(&Vicsek::from_config(&cfg), others, key)
+    }
+
+    #[test]
+    fn vicsek_without_noise_averages_headings() {
+        let behavior: Behavior = decide(
+            0.0,
+            &[
+                (Vec2::new(10.0, 0.0), Vec2::new(0.0, 2.0)),
+                (Vec2::new(500.0, 0.0), Vec2::new(-1.0, 0.0)),
+            ],
+            0,
+        );
+
+        assert!((behavior.velocity.x - 0.5_f32.sqrt()).abs() < 1e-5);
+        assert!((behavior.velocity.y - 0.5_f32.sqrt()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn vicsek_noise_is_bounded() {
+        for key in 0..100 {
+            let behavior: Behavior = decide(0.25, &[], key);
+
+            assert!(behavior.velocity.x >= 45.0_f32.to_radians().cos() - 1e-5);
+            assert!((behavior.velocity.length() - 1.0).abs() < 1e-5);
+        }
+    }
+}
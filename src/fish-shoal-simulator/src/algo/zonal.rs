@@ -0,0 +1,79 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    algo::{Behavior, BehaviorModel, Neighborhood, SchoolingMechanism},
+    Angle, BehaviorParams, Config, StreamRng,
+};
+
+#[derive(Debug, Copy, Clone)]
+pub struct Zonal {
+    avoidance_radius: f32,
+    alignment_radius: f32,
+    attraction_radius: f32,
+    alignment_fov: Angle,
+    attraction_fov: Angle,
+    params: BehaviorParams,
+}
+
+impl Zonal {
+    pub fn from_config(cfg: &Config) -> Self {
+        Self {
+            avoidance_radius: cfg.avoidance_radius,
+            alignment_radius: cfg.alignment_radius,
+            attraction_radius: cfg.attraction_radius,
+            alignment_fov: Angle::from_degrees(cfg.alignment_fov),
+            attraction_fov: Angle::from_degrees(cfg.attraction_fov),
+            params: cfg.behavior,
+        }
+    }
+}
+
+impl BehaviorModel for Zonal {
+    fn decide(&self, fish: &Neighborhood, _rng: &mut StreamRng) -> Behavior {
+        let mut algo: SchoolingMechanism = SchoolingMechanism::setup(
+            fish.position,
+            fish.velocity,
+            fish.speed,
+            fish.stress,
+            fish.others,
+            self.avoidance_radius * fish.perception,
+            self.alignment_radius * fish.perception,
+            self.attraction_radius * fish.perception,
+            self.alignment_fov,
+            self.attraction_fov,
+            fish.boundary,
+        )
+        .with_params(self.params)
+        .with_heading(fish.heading);
+
+        if !algo.avoidance() && !algo.alignment() {
+            algo.attraction();
+        }
+
+        let mut behavior: Behavior = Behavior {
+            velocity: fish.velocity,
+            speed: fish.speed,
+            stress: fish.stress,
+        };
+        algo.set_behavior(
+            &mut behavior.velocity,
+            &mut behavior.speed,
+            &mut behavior.stress,
+        );
+        behavior
+    }
+}
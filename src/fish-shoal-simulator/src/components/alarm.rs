@@ -0,0 +1,38 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use crate::Vec2;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use shipyard::Component;
+
+#[derive(Component, Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Alarm {
+    pub is_alarmed: bool,
+    pub delay: Option<f32>,
+    pub away: Vec2,
+    pub refractory: f32,
+}
+
+impl Alarm {
+    pub fn is_active(&self) -> bool {
+        self.is_alarmed || self.delay.is_some()
+    }
+
+    pub fn can_receive(&self) -> bool {
+        !self.is_active() && self.refractory <= 0.0
+    }
+}
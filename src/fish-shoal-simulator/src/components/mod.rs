@@ -14,22 +14,42 @@
  * limitations under the License.
  */
 
-mod fish_identifier;
-mod social;
+mod alarm;
+mod attack_cooldown;
 mod density;
+mod fish_identifier;
+mod goal_rank;
+mod hunger;
 mod position;
+mod predator_identifier;
+mod predator_target;
+mod rng_key;
+mod safe_position;
+mod social;
+mod species;
 mod speed;
 mod stress;
 mod target_speed;
 mod target_velocity;
+mod traits;
 mod velocity;
 
-pub use fish_identifier::FishIdentifier;
-pub use social::Social;
+pub use alarm::Alarm;
+pub use attack_cooldown::AttackCooldown;
 pub use density::Density;
+pub use fish_identifier::FishIdentifier;
+pub use goal_rank::GoalRank;
+pub use hunger::Hunger;
 pub use position::Position;
+pub use predator_identifier::PredatorIdentifier;
+pub use predator_target::PredatorTarget;
+pub use rng_key::RngKey;
+pub use safe_position::SafePosition;
+pub use social::Social;
+pub use species::Species;
 pub use speed::Speed;
 pub use stress::Stress;
 pub use target_speed::TargetSpeed;
 pub use target_velocity::TargetVelocity;
+pub use traits::Traits;
 pub use velocity::Velocity;
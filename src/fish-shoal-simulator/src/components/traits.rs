@@ -0,0 +1,127 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{TraitDistribution, TraitParams};
+use rand::Rng;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use shipyard::Component;
+use std::f32::consts::TAU;
+
+#[derive(Component, Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Traits {
+    pub body_length: f32,
+    pub speed: f32,
+    pub perception: f32,
+    pub sociability: f32,
+    pub boldness: f32,
+}
+
+impl Traits {
+    const MIN_FACTOR: f32 = 0.05;
+
+    pub fn sample(params: &TraitParams, rng: &mut impl Rng) -> Self {
+        Self {
+            body_length: Self::draw(params.body_length, rng).max(1.0),
+            speed: Self::draw(params.speed, rng).max(Self::MIN_FACTOR),
+            perception: Self::draw(params.perception, rng).max(Self::MIN_FACTOR),
+            sociability: Self::draw(params.sociability, rng).clamp(0.0, 1.0),
+            boldness: Self::draw(params.boldness, rng).clamp(0.0, 1.0),
+        }
+    }
+
+    pub fn mean(params: &TraitParams) -> Self {
+        Self {
+            body_length: params.body_length.mean,
+            speed: params.speed.mean,
+            perception: params.perception.mean,
+            sociability: params.sociability.mean,
+            boldness: params.boldness.mean,
+        }
+    }
+
+    #[inline]
+    pub fn agility(&self, params: &TraitParams) -> f32 {
+        params.body_length.mean / self.body_length
+    }
+
+    // Box-Muller transform, truncated at three standard deviations
+    fn draw(distribution: TraitDistribution, rng: &mut impl Rng) -> f32 {
+        if distribution.std_dev <= 0.0 {
+            return distribution.mean;
+        }
+
+        let radius: f32 = (-2.0 * rng.random_range(f32::EPSILON..1.0).ln()).sqrt();
+        let normal: f32 = radius * (TAU * rng.random::<f32>()).cos();
+
+        distribution.mean + distribution.std_dev * normal.clamp(-3.0, 3.0)
+    }
+}
+
+impl Default for Traits {
+    fn default() -> Self {
+        Self::mean(&TraitParams::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Traits;
+    use crate::{SimRng, StreamRng, TraitDistribution, TraitParams};
+
+    #[test]
+    fn traits_fixed_distribution_is_mean() {
+        let mut rng: StreamRng = SimRng::new(Some(1)).stream(0, SimRng::TRAITS);
+        let params: TraitParams = TraitParams::default();
+
+        assert_eq!(Traits::sample(&params, &mut rng), Traits::mean(&params));
+    }
+
+    #[test]
+    fn traits_sampled_statistics() {
+        let sim_rng: SimRng = SimRng::new(Some(7));
+        let params: TraitParams = TraitParams {
+            speed: TraitDistribution {
+                mean: 1.0,
+                std_dev: 0.2,
+            },
+            sociability: TraitDistribution {
+                mean: 0.9,
+                std_dev: 0.5,
+            },
+            ..TraitParams::default()
+        };
+
+        let samples: Vec<Traits> = (0..10_000)
+            .map(|key| Traits::sample(&params, &mut sim_rng.stream(key, SimRng::TRAITS)))
+            .collect();
+        let speeds: Vec<f32> = samples.iter().map(|traits| traits.speed).collect();
+        let mean: f32 = speeds.iter().sum::<f32>() / speeds.len() as f32;
+        let variance: f32 = speeds
+            .iter()
+            .map(|speed| (speed - mean).powi(2))
+            .sum::<f32>()
+            / speeds.len() as f32;
+
+        assert!((mean - 1.0).abs() < 0.01);
+        assert!((variance.sqrt() - 0.2).abs() < 0.01);
+        assert!(speeds.iter().all(|speed| (speed - 1.0).abs() <= 0.6 + 1e-6));
+        assert!(samples
+            .iter()
+            .all(|traits| (0.0..=1.0).contains(&traits.sociability)));
+    }
+}
@@ -0,0 +1,370 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{Config, Error, SpeciesInteraction, MAX_SPECIES};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::{fs, path::Path};
+
+pub const CONFIG_VERSION: u64 = 2;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ConfigFormat {
+    Toml,
+    Json,
+}
+
+impl ConfigFormat {
+    pub fn from_path(path: &Path) -> Result<Self, Error> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Ok(Self::Toml),
+            Some("json") => Ok(Self::Json),
+            _ => Err(Error::Config(format!(
+                "{}: expected a .toml or .json file",
+                path.display()
+            ))),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ConfigFile<'a> {
+    version: u64,
+    #[serde(flatten)]
+    config: &'a Config,
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let format: ConfigFormat = ConfigFormat::from_path(path)?;
+        let text: String = fs::read_to_string(path)
+            .map_err(|err| Error::Config(format!("{}: {err}", path.display())))?;
+
+        Self::parse(&text, format).map_err(|err| match err {
+            Error::Config(msg) => Error::Config(format!("{}: {msg}", path.display())),
+            other => other,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let text: String = self.serialize(ConfigFormat::from_path(path)?)?;
+        fs::write(path, text).map_err(|err| Error::Config(format!("{}: {err}", path.display())))
+    }
+
+    pub fn parse(text: &str, format: ConfigFormat) -> Result<Self, Error> {
+        let value: Value = match format {
+            ConfigFormat::Toml => {
+                toml::from_str(text).map_err(|err| Error::Config(err.to_string()))
+            }
+            ConfigFormat::Json => {
+                serde_json::from_str(text).map_err(|err| Error::Config(err.to_string()))
+            }
+        }?;
+
+        let cfg: Self = serde_json::from_value(Self::migrate(value)?)
+            .map_err(|err| Error::Config(err.to_string()))?;
+
+        cfg.validate()?;
+        Ok(cfg)
+    }
+
+    pub fn serialize(&self, format: ConfigFormat) -> Result<String, Error> {
+        self.validate()?;
+
+        let file: ConfigFile = ConfigFile {
+            version: CONFIG_VERSION,
+            config: self,
+        };
+
+        match format {
+            ConfigFormat::Toml => {
+                toml::to_string_pretty(&file).map_err(|err| Error::Config(err.to_string()))
+            }
+            ConfigFormat::Json => {
+                serde_json::to_string_pretty(&file).map_err(|err| Error::Config(err.to_string()))
+            }
+        }
+    }
+
+    pub(crate) fn migrate(value: Value) -> Result<Value, Error> {
+        let Value::Object(mut fields) = value else {
+            return Err(Error::Config("expected a table of settings".to_string()));
+        };
+
+        let version: u64 = match fields.remove("version") {
+            None => 0,
+            Some(version) => version.as_u64().ok_or_else(|| {
+                Error::Config(format!("version must be a positive integer, got {version}"))
+            })?,
+        };
+
+        if version > CONFIG_VERSION {
+            return Err(Error::Config(format!(
+                "version {version} is newer than the supported version {CONFIG_VERSION}"
+            )));
+        }
+
+        for from in version..CONFIG_VERSION {
+            fields = Self::upgrade(from, fields);
+        }
+
+        Self::complete_tables(&mut fields)?;
+        Ok(Value::Object(fields))
+    }
+
+    fn upgrade(from: u64, mut fields: Map<String, Value>) -> Map<String, Value> {
+        match from {
+            0 => fields,
+            1 => {
+                let mut first: Map<String, Value> = Map::new();
+                if let Some(count) = fields.remove("entity_count") {
+                    first.insert("count".to_string(), count);
+                }
+                if let Some(traits) = fields.remove("traits") {
+                    first.insert("traits".to_string(), traits);
+                }
+                if !first.is_empty() {
+                    fields.insert(
+                        "species".to_string(),
+                        Value::Array(vec![Value::Object(first)]),
+                    );
+                }
+                fields
+            }
+            _ => unreachable!("no migration from config version {from}"),
+        }
+    }
+
+    fn complete_tables(fields: &mut Map<String, Value>) -> Result<(), Error> {
+        let defaults: Config = Config::default();
+        let align: Value = serde_json::to_value(SpeciesInteraction::Align)
+            .map_err(|err| Error::Config(err.to_string()))?;
+
+        Self::complete_table(fields, "species", &defaults.species, |species| {
+            if let Some(Value::Array(interactions)) = species.get_mut("interactions") {
+                while interactions.len() < MAX_SPECIES {
+                    interactions.push(align.clone());
+                }
+            }
+        })?;
+        Self::complete_table(fields, "goals", &defaults.goals, |_| ())
+    }
+
+    fn complete_table<T: Serialize>(
+        fields: &mut Map<String, Value>,
+        name: &str,
+        defaults: &[T],
+        complete: impl Fn(&mut Map<String, Value>),
+    ) -> Result<(), Error> {
+        let Some(Value::Array(table)) = fields.get_mut(name) else {
+            return Ok(());
+        };
+
+        if table.len() > defaults.len() {
+            return Err(Error::Config(format!(
+                "at most {} {name} are supported, got {}",
+                defaults.len(),
+                table.len()
+            )));
+        }
+
+        for (index, default) in defaults.iter().enumerate() {
+            let Value::Object(mut entry) =
+                serde_json::to_value(default).map_err(|err| Error::Config(err.to_string()))?
+            else {
+                unreachable!("{name} parameters serialize to a table");
+            };
+
+            match table.get_mut(index) {
+                Some(Value::Object(given)) => {
+                    complete(given);
+                    entry.append(given);
+                    *given = entry;
+                }
+                Some(_) => (),
+                None => table.push(Value::Object(entry)),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConfigFormat, CONFIG_VERSION};
+    use crate::{
+        BehaviorParams, BoundaryMode, Config, Error, GoalKind, SpeciesInteraction, StressMode,
+        TraitDistribution,
+    };
+
+    fn custom() -> Config {
+        let mut cfg: Config = Config {
+            boundary_mode: BoundaryMode::Reflect,
+            attraction_radius: 80.0,
+            behavior: BehaviorParams {
+                alone_speed: 30.0,
+                ..BehaviorParams::default()
+            },
+            ..Config::default()
+        };
+        cfg.species[0].count = 42;
+        cfg.species[1].count = 7;
+        cfg.species[1].interactions[0] = SpeciesInteraction::Avoid;
+        cfg.informed_fraction = 0.1;
+        cfg.goals[1].kind = GoalKind::Point;
+        cfg.goals[1].share = 0.5;
+        cfg.stress_mode = StressMode::Contagion;
+        cfg.stress.transmission_probability = 0.8;
+        cfg
+    }
+
+    #[test]
+    fn config_file_round_trip() {
+        for format in [ConfigFormat::Toml, ConfigFormat::Json] {
+            let text: String = custom().serialize(format).unwrap();
+            assert_eq!(Config::parse(&text, format).unwrap(), custom());
+        }
+    }
+
+    #[test]
+    fn config_file_is_versioned() {
+        let text: String = custom().serialize(ConfigFormat::Toml).unwrap();
+        assert!(text.starts_with(&format!("version = {CONFIG_VERSION}\n")));
+    }
+
+    #[test]
+    fn config_file_unversioned_is_migrated() {
+        let cfg: Config = Config::parse(r#"{ "entity_count": 42 }"#, ConfigFormat::Json).unwrap();
+        assert_eq!(cfg.species[0].count, 42);
+        assert_eq!(cfg.width, Config::default().width);
+    }
+
+    #[test]
+    fn config_file_species_are_migrated() {
+        let cfg: Config = Config::parse(
+            "version = 1
+entity_count = 42
+[traits]
+speed = { mean = 1.2, std_dev = 0.1 }",
+            ConfigFormat::Toml,
+        )
+        .unwrap();
+
+        assert_eq!(cfg.species[0].count, 42);
+        assert_eq!(
+            cfg.species[0].traits.speed,
+            TraitDistribution {
+                mean: 1.2,
+                std_dev: 0.1,
+            }
+        );
+        assert_eq!(cfg.species[0].color, Config::default().species[0].color);
+        assert_eq!(cfg.species[1..], Config::default().species[1..]);
+    }
+
+    #[test]
+    fn config_file_partial_species_table() {
+        let cfg: Config = Config::parse(
+            "version = 2
+\
+             [[species]]
+count = 10
+\
+             [[species]]
+count = 5
+interactions = [\"Avoid\"]",
+            ConfigFormat::Toml,
+        )
+        .unwrap();
+
+        assert_eq!(cfg.fish_count(), 15);
+        assert_eq!(cfg.species[1].color, Config::default().species[1].color);
+        assert_eq!(cfg.interaction(1, 0), SpeciesInteraction::Avoid);
+        assert_eq!(cfg.interaction(1, 1), SpeciesInteraction::Align);
+        assert_eq!(cfg.species[3], Config::default().species[3]);
+
+        let result: Result<Config, Error> =
+            Config::parse(&"[[species]]\n".repeat(5), ConfigFormat::Toml);
+        assert!(matches!(result, Err(Error::Config(msg)) if msg.starts_with("at most")));
+    }
+
+    #[test]
+    fn config_file_partial_goals() {
+        let cfg: Config = Config::parse(
+            "version = 2
+informed_fraction = 0.2
+
+[[goals]]
+kind = \"Point\"
+point = [100.0, 200.0]",
+            ConfigFormat::Toml,
+        )
+        .unwrap();
+
+        assert_eq!(cfg.goals[0].kind, GoalKind::Point);
+        assert_eq!(cfg.goals[0].point, [100.0, 200.0]);
+        assert_eq!(cfg.goals[0].share, Config::default().goals[0].share);
+        assert_eq!(cfg.goals[1], Config::default().goals[1]);
+
+        let result: Result<Config, Error> =
+            Config::parse(&"[[goals]]\n".repeat(3), ConfigFormat::Toml);
+        assert!(matches!(result, Err(Error::Config(msg)) if msg.starts_with("at most 2 goals")));
+    }
+
+    #[test]
+    fn config_file_rejects_newer_version() {
+        let text: String = format!("version = {}\n", CONFIG_VERSION + 1);
+        assert!(matches!(
+            Config::parse(&text, ConfigFormat::Toml),
+            Err(Error::Config(_))
+        ));
+    }
+
+    #[test]
+    fn config_file_rejects_unknown_field() {
+        let result: Result<Config, Error> = Config::parse("entity_cont = 3", ConfigFormat::Toml);
+        assert!(matches!(result, Err(Error::Config(msg)) if msg.contains("entity_cont")));
+    }
+
+    #[test]
+    fn config_file_behavior_section() {
+        let cfg: Config = Config::parse(
+            "entity_count = 42\n[behavior]\nalone_speed = 30.0\nlerp_rate = 2.0",
+            ConfigFormat::Toml,
+        )
+        .unwrap();
+        assert_eq!(cfg.behavior.alone_speed, 30.0);
+        assert_eq!(cfg.behavior.lerp_rate, 2.0);
+        assert_eq!(
+            cfg.behavior.avoidance_speed,
+            BehaviorParams::default().avoidance_speed
+        );
+
+        let result: Result<Config, Error> =
+            Config::parse("[behavior]\nalone_sped = 30.0", ConfigFormat::Toml);
+        assert!(matches!(result, Err(Error::Config(msg)) if msg.contains("alone_sped")));
+    }
+
+    #[test]
+    fn config_file_validates() {
+        let result: Result<Config, Error> = Config::parse(
+            "avoidance_radius = 40.0\nalignment_radius = 30.0",
+            ConfigFormat::Toml,
+        );
+        assert!(matches!(result, Err(Error::Config(msg)) if msg.starts_with("radii")));
+    }
+}
@@ -15,55 +15,89 @@
  */
 
 use crate::{
-    Config, FishIdentifier, Social, Density, Position, Scalar, Speed, Stress, TargetSpeed,
-    TargetVelocity, Vec2, Velocity,
+    Alarm, Config, Density, FishIdentifier, GoalRank, Position, RngKey, SafePosition, Scalar,
+    SimRng, Social, Species, Speed, StreamRng, Stress, TargetSpeed, TargetVelocity, Traits, Vec2,
+    Velocity,
 };
-use rand::{rngs::ThreadRng, seq::SliceRandom};
-use shipyard::{EntityId, IntoIter, View, World};
+use rand::{seq::SliceRandom, Rng};
+use shipyard::{EntityId, IntoIter, UniqueView, UniqueViewMut, View, World};
 
 #[derive(Debug)]
 pub struct Fish;
 
 impl Fish {
-    pub fn add(world: &mut World, amount: usize, cfg: Config) {
-        let mut rng: ThreadRng = rand::rng();
+    pub fn add(world: &mut World, species: usize, amount: usize, cfg: Config) {
+        let streams: Vec<(u64, StreamRng, StreamRng, GoalRank)> =
+            world.run(|mut sim_rng: UniqueViewMut<SimRng>| {
+                (0..amount)
+                    .map(|_| {
+                        let key: u64 = sim_rng.next_key();
+                        (
+                            key,
+                            sim_rng.stream(key, SimRng::SPAWN),
+                            sim_rng.stream(key, SimRng::TRAITS),
+                            GoalRank(sim_rng.stream(key, SimRng::GOALS).random()),
+                        )
+                    })
+                    .collect()
+            });
+
+        for (key, mut rng, mut traits_rng, goal_rank) in streams {
+            let traits: Traits = Traits::sample(&cfg.species[species].traits, &mut traits_rng);
+            let position: Vec2 =
+                Vec2::new_random(&mut rng, 0.0..cfg.width as f32, 0.0..cfg.height as f32);
 
-        for _ in 0..amount {
             world.add_entity((
                 FishIdentifier,
-                Position(Vec2::new_random(
-                    &mut rng,
-                    0.0..cfg.width as f32,
-                    0.0..cfg.height as f32,
-                )),
+                RngKey(key),
+                Position(position),
+                SafePosition(position),
                 Velocity(Vec2::random_dir(&mut rng)),
                 TargetVelocity(Vec2::random_dir(&mut rng)),
                 Speed(Scalar::ZERO),
-                TargetSpeed(Scalar::new_random(&mut rng, 50.0..100.0)),
-                Stress(Scalar::new(0.1)),
+                TargetSpeed(Scalar::new_random(
+                    &mut rng,
+                    cfg.behavior.spawn_speed_min * traits.speed
+                        ..cfg.behavior.spawn_speed_max * traits.speed,
+                )),
+                Stress(Scalar::new(cfg.behavior.spawn_stress)),
                 Density::default(),
                 Social::default(),
+                Species(species),
+                traits,
+                goal_rank,
+                Alarm::default(),
             ));
         }
     }
 
-    pub fn remove(world: &mut World, amount: usize) {
-        let maybe_ids: Option<Vec<EntityId>> = world.run(|fish: View<FishIdentifier>| {
-            let ids: Vec<EntityId> = (&fish).iter().with_id().map(|(id, _)| id).collect();
+    pub fn remove(world: &mut World, species: usize, amount: usize) {
+        let maybe_ids: Option<Vec<EntityId>> = world.run(
+            |fish: View<FishIdentifier>,
+             fish_species: View<Species>,
+             sim_rng: UniqueView<SimRng>| {
+                let ids: Vec<EntityId> = (&fish, &fish_species)
+                    .iter()
+                    .with_id()
+                    .filter(|(_, (_, fish_species))| fish_species.0 == species)
+                    .map(|(id, _)| id)
+                    .collect();
 
-            if ids.is_empty() {
-                return None;
-            }
+                if ids.is_empty() {
+                    return None;
+                }
 
-            let mut rng: ThreadRng = rand::rng();
-            let count: usize = amount.min(ids.len());
+                let mut rng: StreamRng = sim_rng.stream(ids.len() as u64, SimRng::DESPAWN);
+                let count: usize = amount.min(ids.len());
 
-            let mut indices: Vec<usize> = (0..ids.len()).collect();
-            indices.shuffle(&mut rng);
-            let chosen: Vec<EntityId> = indices.into_iter().take(count).map(|i| ids[i]).collect();
+                let mut indices: Vec<usize> = (0..ids.len()).collect();
+                indices.shuffle(&mut rng);
+                let chosen: Vec<EntityId> =
+                    indices.into_iter().take(count).map(|i| ids[i]).collect();
 
-            Some(chosen)
-        });
+                Some(chosen)
+            },
+        );
 
         if let Some(ids) = maybe_ids {
             for id in ids {
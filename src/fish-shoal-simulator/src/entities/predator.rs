@@ -0,0 +1,75 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    AttackCooldown, Config, Hunger, Position, PredatorIdentifier, PredatorTarget, RngKey,
+    SafePosition, Scalar, SimRng, Speed, StreamRng, Stress, TargetSpeed, TargetVelocity, Vec2,
+    Velocity,
+};
+use shipyard::{EntityId, IntoIter, UniqueViewMut, View, World};
+
+#[derive(Debug)]
+pub struct Predator;
+
+impl Predator {
+    pub fn add(world: &mut World, amount: usize, cfg: Config) {
+        let streams: Vec<(u64, StreamRng)> = world.run(|mut sim_rng: UniqueViewMut<SimRng>| {
+            (0..amount)
+                .map(|_| {
+                    let key: u64 = sim_rng.next_key();
+                    (key, sim_rng.stream(key, SimRng::SPAWN))
+                })
+                .collect()
+        });
+
+        for (key, mut rng) in streams {
+            let position: Vec2 =
+                Vec2::new_random(&mut rng, 0.0..cfg.width as f32, 0.0..cfg.height as f32);
+
+            world.add_entity((
+                PredatorIdentifier,
+                RngKey(key),
+                Position(position),
+                SafePosition(position),
+                Velocity(Vec2::random_dir(&mut rng)),
+                TargetVelocity(Vec2::random_dir(&mut rng)),
+                Speed(Scalar::ZERO),
+                TargetSpeed(Scalar::new(
+                    cfg.predator_speed * cfg.behavior.wander_speed_factor,
+                )),
+                Stress(Scalar::new(cfg.behavior.predator_stress)),
+                Hunger(Scalar::ONE),
+                PredatorTarget::default(),
+                AttackCooldown::default(),
+            ));
+        }
+    }
+
+    pub fn remove(world: &mut World, amount: usize) {
+        let ids: Vec<EntityId> = world.run(|predators: View<PredatorIdentifier>| {
+            (&predators)
+                .iter()
+                .with_id()
+                .map(|(id, _)| id)
+                .take(amount)
+                .collect()
+        });
+
+        for id in ids {
+            let _ = world.delete_entity(id);
+        }
+    }
+}
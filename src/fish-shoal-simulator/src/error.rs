@@ -24,6 +24,7 @@ pub enum Error {
     Create(String),
     Config(String),
     Run(String),
+    Snapshot(String),
 }
 
 impl Display for Error {
@@ -35,6 +36,7 @@ impl Display for Error {
                 Self::Create(err) => format!("create: {err}"),
                 Self::Config(err) => format!("config: {err}"),
                 Self::Run(err) => format!("run: {err}"),
+                Self::Snapshot(err) => format!("snapshot: {err}"),
             }
         )
     }
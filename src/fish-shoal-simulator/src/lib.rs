@@ -15,18 +15,30 @@
  */
 
 mod algo;
+mod command;
 mod components;
+#[cfg(feature = "serde")]
+mod config_file;
 mod entities;
 mod error;
+mod metrics;
 mod simulator;
+mod simulator_builder;
 mod simulator_output;
+mod snapshot;
 mod systems;
 mod types;
 mod uniques;
 
+pub use command::Command;
 pub use components::*;
+#[cfg(feature = "serde")]
+pub use config_file::{ConfigFormat, CONFIG_VERSION};
 pub use error::Error;
+pub use metrics::Metrics;
 pub use simulator::FishShoalSimulator;
+pub use simulator_builder::FishShoalSimulatorBuilder;
 pub use simulator_output::SimulatorOutput;
+pub use snapshot::{FishState, PredatorState, Snapshot, SNAPSHOT_VERSION};
 pub use types::*;
 pub use uniques::*;
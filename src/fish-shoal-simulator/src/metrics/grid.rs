@@ -0,0 +1,95 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{Boundary, Chunks, Vec2};
+
+const MAX_CELLS_PER_AXIS: f32 = 256.0;
+
+#[derive(Debug)]
+pub struct Grid {
+    cell_width: f32,
+    cell_height: f32,
+    cols: i32,
+    rows: i32,
+    periodic: bool,
+    cells: Vec<Vec<usize>>,
+}
+
+impl Grid {
+    pub fn new(positions: &[Vec2], boundary: Boundary, cell_size: f32) -> Self {
+        let min_cell_size: f32 = boundary.width.max(boundary.height) / MAX_CELLS_PER_AXIS;
+        let cell_size: f32 = cell_size.max(min_cell_size).max(1.0);
+
+        let periodic: bool = boundary.is_periodic();
+
+        let (cols, cell_width): (usize, f32) = Chunks::tiling(boundary.width, cell_size, periodic);
+        let (rows, cell_height): (usize, f32) =
+            Chunks::tiling(boundary.height, cell_size, periodic);
+        let (cols, rows): (i32, i32) = (cols as i32, rows as i32);
+
+        let mut grid: Self = Self {
+            cell_width,
+            cell_height,
+            cols,
+            rows,
+            periodic,
+            cells: vec![Vec::new(); (cols * rows) as usize],
+        };
+
+        for (idx, &pos) in positions.iter().enumerate() {
+            let (x, y): (i32, i32) = grid.cell_of(pos);
+            grid.cells[(y * cols + x) as usize].push(idx);
+        }
+
+        grid
+    }
+
+    #[inline]
+    pub fn cell_size(&self) -> f32 {
+        self.cell_width.min(self.cell_height)
+    }
+
+    #[inline]
+    pub fn max_ring(&self) -> i32 {
+        self.cols.max(self.rows)
+    }
+
+    pub fn cell_of(&self, pos: Vec2) -> (i32, i32) {
+        (
+            ((pos.x / self.cell_width).floor() as i32).clamp(0, self.cols - 1),
+            ((pos.y / self.cell_height).floor() as i32).clamp(0, self.rows - 1),
+        )
+    }
+
+    pub fn ring(&self, (cx, cy): (i32, i32), k: i32) -> impl Iterator<Item = usize> + '_ {
+        (-k..=k)
+            .flat_map(move |dx| (-k..=k).map(move |dy| (dx, dy)))
+            .filter(move |(dx, dy)| dx.abs().max(dy.abs()) == k)
+            .filter_map(move |(dx, dy)| self.cell_index(cx + dx, cy + dy))
+            .flat_map(|cell| self.cells[cell].iter().copied())
+    }
+
+    fn cell_index(&self, mut x: i32, mut y: i32) -> Option<usize> {
+        if self.periodic {
+            x = x.rem_euclid(self.cols);
+            y = y.rem_euclid(self.rows);
+        } else if !(0..self.cols).contains(&x) || !(0..self.rows).contains(&y) {
+            return None;
+        }
+
+        Some((y * self.cols + x) as usize)
+    }
+}
@@ -0,0 +1,324 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::{Grid, UnionFind};
+use crate::{Boundary, GoalParams, Vec2, MAX_GOALS};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::f32::consts::TAU;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Metrics {
+    pub polarization: f32,
+    pub milling: f32,
+    pub mean_nearest_neighbor_distance: f32,
+    pub average_speed: f32,
+    pub cluster_count: usize,
+    pub largest_cluster: usize,
+    pub informed_count: usize,
+    pub goal_accuracy: [f32; MAX_GOALS],
+    pub alarmed_count: usize,
+    pub wave_reach: usize,
+    pub wave_distance: f32,
+    pub wave_speed: f32,
+}
+
+impl Metrics {
+    pub fn compute(
+        positions: &[[f32; 2]],
+        velocities: &[[f32; 2]],
+        speeds: &[f32],
+        boundary: Boundary,
+        link_radius: f32,
+    ) -> Self {
+        if positions.is_empty() {
+            return Self::default();
+        }
+
+        let positions: Vec<Vec2> = positions.iter().copied().map(Vec2::from).collect();
+        let headings: Vec<Vec2> = velocities
+            .iter()
+            .map(|&vel| Vec2::from(vel).normalized())
+            .collect();
+
+        let grid: Grid = Grid::new(&positions, boundary, link_radius);
+        let (cluster_count, largest_cluster): (usize, usize) =
+            Self::clusters(&positions, &grid, boundary, link_radius);
+
+        Self {
+            polarization: Self::polarization(&headings),
+            milling: Self::milling(&positions, &headings, boundary),
+            mean_nearest_neighbor_distance: Self::mean_nearest_neighbor_distance(
+                &positions, &grid, boundary,
+            ),
+            average_speed: speeds.iter().sum::<f32>() / speeds.len().max(1) as f32,
+            cluster_count,
+            largest_cluster,
+            ..Self::default()
+        }
+    }
+
+    pub fn goal_accuracy(
+        positions: &[[f32; 2]],
+        velocities: &[[f32; 2]],
+        informed: &[Option<usize>],
+        goals: &[GoalParams; MAX_GOALS],
+        boundary: Boundary,
+    ) -> [f32; MAX_GOALS] {
+        let mut accuracy: [f32; MAX_GOALS] = [0.0; MAX_GOALS];
+        if positions.is_empty() {
+            return accuracy;
+        }
+
+        for (index, goal) in goals.iter().enumerate() {
+            if !informed.contains(&Some(index)) {
+                continue;
+            }
+
+            let total: f32 = positions
+                .iter()
+                .zip(velocities)
+                .map(|(&pos, &vel)| {
+                    Vec2::from(vel)
+                        .normalized()
+                        .dot(goal.heading(pos.into(), boundary))
+                })
+                .sum();
+            accuracy[index] = total / positions.len() as f32;
+        }
+
+        accuracy
+    }
+
+    fn polarization(headings: &[Vec2]) -> f32 {
+        let sum: Vec2 = headings
+            .iter()
+            .fold(Vec2::ZERO, |acc, &heading| acc + heading);
+        sum.length() / headings.len() as f32
+    }
+
+    fn milling(positions: &[Vec2], headings: &[Vec2], boundary: Boundary) -> f32 {
+        let center: Vec2 = Self::centroid(positions, boundary);
+
+        let angular_momentum: f32 = positions
+            .iter()
+            .zip(headings)
+            .map(|(&pos, &heading)| {
+                let radial: Vec2 = boundary.offset(center, pos).normalized();
+                radial.x * heading.y - radial.y * heading.x
+            })
+            .sum();
+
+        angular_momentum.abs() / positions.len() as f32
+    }
+
+    fn centroid(positions: &[Vec2], boundary: Boundary) -> Vec2 {
+        if !boundary.is_periodic() {
+            let sum: Vec2 = positions.iter().fold(Vec2::ZERO, |acc, &pos| acc + pos);
+            return sum / positions.len() as f32;
+        }
+
+        Vec2::new(
+            Self::circular_mean(positions.iter().map(|pos| pos.x), boundary.width),
+            Self::circular_mean(positions.iter().map(|pos| pos.y), boundary.height),
+        )
+    }
+
+    fn circular_mean(values: impl Iterator<Item = f32>, size: f32) -> f32 {
+        let (sin, cos): (f32, f32) = values.fold((0.0, 0.0), |(sin, cos), value| {
+            let (s, c): (f32, f32) = (value / size * TAU).sin_cos();
+            (sin + s, cos + c)
+        });
+
+        (sin.atan2(cos) / TAU * size).rem_euclid(size)
+    }
+
+    fn mean_nearest_neighbor_distance(positions: &[Vec2], grid: &Grid, boundary: Boundary) -> f32 {
+        if positions.len() < 2 {
+            return 0.0;
+        }
+
+        let total: f32 = positions
+            .iter()
+            .enumerate()
+            .map(|(idx, &pos)| {
+                let cell: (i32, i32) = grid.cell_of(pos);
+                let mut nearest: f32 = f32::INFINITY;
+
+                for k in 0..=grid.max_ring() {
+                    for other in grid.ring(cell, k).filter(|&other| other != idx) {
+                        nearest = nearest.min(boundary.distance(pos, positions[other]));
+                    }
+                    if nearest <= k as f32 * grid.cell_size() {
+                        break;
+                    }
+                }
+
+                nearest
+            })
+            .sum();
+
+        total / positions.len() as f32
+    }
+
+    fn clusters(
+        positions: &[Vec2],
+        grid: &Grid,
+        boundary: Boundary,
+        link_radius: f32,
+    ) -> (usize, usize) {
+        let mut sets: UnionFind = UnionFind::new(positions.len());
+        let reach: i32 = (link_radius / grid.cell_size()).ceil() as i32;
+
+        for (idx, &pos) in positions.iter().enumerate() {
+            let cell: (i32, i32) = grid.cell_of(pos);
+            for k in 0..=reach {
+                for other in grid.ring(cell, k).filter(|&other| other > idx) {
+                    if boundary.distance(pos, positions[other]) <= link_radius {
+                        sets.union(idx, other);
+                    }
+                }
+            }
+        }
+
+        let groups: Vec<usize> = sets.groups();
+        (groups.len(), groups.into_iter().max().unwrap_or(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Metrics;
+    use crate::{Boundary, BoundaryMode, Config, GoalKind};
+    use std::f32::consts::TAU;
+
+    fn walls() -> Boundary {
+        Boundary::new(BoundaryMode::Reflect, 1_000.0, 1_000.0)
+    }
+
+    #[test]
+    fn metrics_empty() {
+        assert_eq!(
+            Metrics::compute(&[], &[], &[], walls(), 10.0),
+            Metrics::default()
+        );
+    }
+
+    #[test]
+    fn metrics_aligned_shoal_is_polarized() {
+        let positions: Vec<[f32; 2]> = (0..10).map(|i| [100.0 + i as f32 * 5.0, 100.0]).collect();
+        let velocities: Vec<[f32; 2]> = vec![[0.0, 1.0]; 10];
+        let speeds: Vec<f32> = vec![50.0; 10];
+
+        let metrics: Metrics = Metrics::compute(&positions, &velocities, &speeds, walls(), 6.0);
+
+        assert!((metrics.polarization - 1.0).abs() < 1e-5);
+        assert!((metrics.mean_nearest_neighbor_distance - 5.0).abs() < 1e-4);
+        assert_eq!(metrics.average_speed, 50.0);
+        assert_eq!(metrics.cluster_count, 1);
+        assert_eq!(metrics.largest_cluster, 10);
+    }
+
+    #[test]
+    fn metrics_opposed_headings_cancel() {
+        let positions: Vec<[f32; 2]> = vec![[100.0, 100.0], [200.0, 200.0]];
+        let velocities: Vec<[f32; 2]> = vec![[1.0, 0.0], [-1.0, 0.0]];
+
+        let metrics: Metrics =
+            Metrics::compute(&positions, &velocities, &[1.0, 3.0], walls(), 10.0);
+
+        assert!(metrics.polarization.abs() < 1e-5);
+        assert_eq!(metrics.average_speed, 2.0);
+        assert_eq!(metrics.cluster_count, 2);
+        assert_eq!(metrics.largest_cluster, 1);
+    }
+
+    #[test]
+    fn metrics_milling_ring() {
+        let count: usize = 36;
+        let (positions, velocities): (Vec<[f32; 2]>, Vec<[f32; 2]>) = (0..count)
+            .map(|i| {
+                let (sin, cos): (f32, f32) = (i as f32 / count as f32 * TAU).sin_cos();
+                ([500.0 + cos * 100.0, 500.0 + sin * 100.0], [-sin, cos])
+            })
+            .unzip();
+
+        let metrics: Metrics =
+            Metrics::compute(&positions, &velocities, &vec![1.0; count], walls(), 20.0);
+
+        assert!((metrics.milling - 1.0).abs() < 1e-4);
+        assert!(metrics.polarization < 1e-4);
+    }
+
+    #[test]
+    fn metrics_clusters_link_across_wrap_seam() {
+        let positions: Vec<[f32; 2]> = vec![[1.0, 500.0], [998.0, 500.0], [500.0, 500.0]];
+        let velocities: Vec<[f32; 2]> = vec![[1.0, 0.0]; 3];
+        let wrap: Boundary = Boundary::new(BoundaryMode::Wrap, 1_000.0, 1_000.0);
+
+        let metrics: Metrics = Metrics::compute(&positions, &velocities, &[1.0; 3], wrap, 5.0);
+        assert_eq!(metrics.cluster_count, 2);
+        assert_eq!(metrics.largest_cluster, 2);
+
+        let metrics: Metrics = Metrics::compute(&positions, &velocities, &[1.0; 3], walls(), 5.0);
+        assert_eq!(metrics.cluster_count, 3);
+    }
+
+    #[test]
+    fn metrics_link_across_partial_wrap_seam() {
+        let positions: Vec<[f32; 2]> = vec![[10.0, 500.0], [1_890.0, 500.0], [1_840.0, 500.0]];
+        let velocities: Vec<[f32; 2]> = vec![[1.0, 0.0]; 3];
+        let wrap: Boundary = Boundary::new(BoundaryMode::Wrap, 1_920.0, 1_080.0);
+
+        let metrics: Metrics =
+            Metrics::compute(&positions[..2], &velocities[..2], &[1.0; 2], wrap, 50.0);
+        assert_eq!(metrics.cluster_count, 1);
+
+        let metrics: Metrics = Metrics::compute(&positions, &velocities, &[1.0; 3], wrap, 50.0);
+        assert!((metrics.mean_nearest_neighbor_distance - 130.0 / 3.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn metrics_goal_accuracy() {
+        let mut cfg: Config = Config::default();
+        cfg.goals[1].kind = GoalKind::Point;
+        cfg.goals[1].point = [500.0, 0.0];
+
+        let positions: Vec<[f32; 2]> = vec![[100.0, 100.0], [200.0, 100.0], [300.0, 100.0]];
+        let velocities: Vec<[f32; 2]> = vec![[1.0, 0.0], [2.0, 0.0], [0.0, 1.0]];
+
+        let accuracy: [f32; 2] = Metrics::goal_accuracy(
+            &positions,
+            &velocities,
+            &[Some(0), None, None],
+            &cfg.goals,
+            walls(),
+        );
+        assert!((accuracy[0] - 2.0 / 3.0).abs() < 1e-5);
+        assert_eq!(accuracy[1], 0.0);
+
+        let accuracy: [f32; 2] = Metrics::goal_accuracy(
+            &positions,
+            &velocities,
+            &[Some(1), Some(1), None],
+            &cfg.goals,
+            walls(),
+        );
+        assert_eq!(accuracy[0], 0.0);
+        assert!(accuracy[1] > 0.0 && accuracy[1] < 1.0);
+    }
+}
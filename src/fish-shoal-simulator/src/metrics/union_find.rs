@@ -0,0 +1,80 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[derive(Debug)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl UnionFind {
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+        }
+    }
+
+    pub fn find(&mut self, mut idx: usize) -> usize {
+        while self.parents[idx] != idx {
+            self.parents[idx] = self.parents[self.parents[idx]];
+            idx = self.parents[idx];
+        }
+        idx
+    }
+
+    pub fn union(&mut self, a: usize, b: usize) {
+        let (mut root_a, mut root_b): (usize, usize) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return;
+        }
+
+        if self.sizes[root_a] < self.sizes[root_b] {
+            (root_a, root_b) = (root_b, root_a);
+        }
+
+        self.parents[root_b] = root_a;
+        self.sizes[root_a] += self.sizes[root_b];
+    }
+
+    pub fn groups(&mut self) -> Vec<usize> {
+        let roots: Vec<usize> = (0..self.parents.len())
+            .filter(|&idx| self.find(idx) == idx)
+            .collect();
+
+        roots.into_iter().map(|root| self.sizes[root]).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnionFind;
+
+    #[test]
+    fn union_find_groups() {
+        let mut sets: UnionFind = UnionFind::new(5);
+        sets.union(0, 1);
+        sets.union(3, 4);
+        sets.union(1, 0);
+
+        let mut groups: Vec<usize> = sets.groups();
+        groups.sort();
+
+        assert_eq!(groups, vec![1, 2, 2]);
+        assert_eq!(sets.find(0), sets.find(1));
+        assert_ne!(sets.find(0), sets.find(3));
+    }
+}
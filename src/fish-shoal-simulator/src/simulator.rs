@@ -15,100 +15,706 @@
  */
 
 use crate::{
-    entities::Fish, systems::*, Chunks, Config, DeltaTime, Density, Error, Position, SimulatorOutput,
-    Speed, Velocity,
+    entities::{Fish, Predator},
+    systems::CalculateDeltaTime,
+    AlarmWave, Boundary, Captures, Chunks, ClockMode, Command, Config, DeltaTime, Error,
+    FishIdentifier, FishShoalSimulatorBuilder, Obstacles, Position, SimRng, SimulatorOutput,
+    Snapshot, Species, Stress, Vec2, MAX_SPECIES,
 };
 use shipyard::{
-    error::{AddWorkload, RunWorkload},
-    Workload,
-    {UniqueView, UniqueViewMut, View, World},
+    error::RunWorkload,
+    EntityId, Get, IntoIter, {UniqueView, UniqueViewMut, View, ViewMut, World},
 };
 use std::{cmp::Ordering, mem};
 
 #[derive(Debug)]
 pub struct FishShoalSimulator {
     world: World,
-    paused: bool,
+    config: Config,
+    pending_snapshot: Option<Box<Snapshot>>,
+    captured: [usize; MAX_SPECIES],
 }
 
 impl FishShoalSimulator {
     pub fn new() -> Result<Self, Error> {
-        let mut world: World = World::default();
-        let cfg: Config = Config::default();
-
-        world.add_unique(Config::default());
-        world.add_unique(DeltaTime::default());
-        world.add_unique(Chunks::new(cfg.attraction_radius));
-
-        Fish::add(&mut world, cfg.entity_count, cfg);
-
-        Workload::new("sim")
-            .with_system(CalculateDeltaTime::system)
-            .with_system(LoadChunks::system)
-            .with_barrier()
-            .with_system(Motion::system)
-            .with_system(OutOfBound::system)
-            .with_system(LerpToTarget::system)
-            .with_system(RandomBehavior::system)
-            .with_system(Swarming::system)
-            .add_to_world(&world)
-            .map_err(|err: AddWorkload| Error::Create(err.to_string()))?;
-
-        Ok(Self {
+        Self::builder().build()
+    }
+
+    pub fn builder() -> FishShoalSimulatorBuilder {
+        FishShoalSimulatorBuilder::default()
+    }
+
+    pub fn with_seed(seed: Option<u64>) -> Result<Self, Error> {
+        Self::builder().maybe_seed(seed).build()
+    }
+
+    pub fn with_config(cfg: Config, seed: Option<u64>) -> Result<Self, Error> {
+        Self::builder().config(cfg).maybe_seed(seed).build()
+    }
+
+    pub(crate) fn from_world(world: World, config: Config) -> Self {
+        Self {
             world,
-            paused: false,
-        })
+            config,
+            pending_snapshot: None,
+            captured: [0; MAX_SPECIES],
+        }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn config_mut(&mut self) -> &mut Config {
+        &mut self.config
+    }
+
+    pub fn fish_count(&self) -> usize {
+        self.world.run(|fish: View<FishIdentifier>| fish.len())
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.world.run(|sim_rng: UniqueView<SimRng>| sim_rng.seed())
     }
 
     pub fn run<F>(&mut self, mut io: F) -> Result<(), Error>
     where
         F: FnMut(SimulatorOutput) -> Config + 'static,
     {
-        if self.paused {
-            self.world.run(CalculateDeltaTime::system);
-        } else {
+        self.sync_config()?;
+
+        if self.config.paused {
             self.world
-                .run_workload("sim")
-                .map_err(|err: RunWorkload| Error::Run(err.to_string()))?;
+                .run(|mut delta_time: UniqueViewMut<DeltaTime>| delta_time.reset());
+        } else {
+            let substeps: usize = self.world.run(CalculateDeltaTime::system);
+            for _ in 0..substeps {
+                self.run_workload()?;
+            }
+        }
+
+        let mut output: SimulatorOutput = SimulatorOutput::build(&self.world);
+        output.snapshot = self.pending_snapshot.take();
+        output.captured = mem::take(&mut self.captured);
+
+        let new_cfg: Config = io(output);
+
+        if new_cfg != self.config {
+            self.update_config(new_cfg)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn step(&mut self, dt: f32) -> Result<(), Error> {
+        if !dt.is_finite() {
+            return Err(Error::Run(format!("dt must be a finite number, got {dt}")));
+        }
+
+        self.sync_config()?;
+        self.world
+            .run(|mut delta_time: UniqueViewMut<DeltaTime>| delta_time.set(dt));
+        self.run_workload()
+    }
+
+    pub fn step_n(&mut self, n: usize, dt: f32) -> Result<(), Error> {
+        for _ in 0..n {
+            self.step(dt)?;
         }
+        Ok(())
+    }
+
+    pub fn output(&self, output: &mut SimulatorOutput) {
+        output.fill(&self.world);
+    }
+
+    pub fn execute(&mut self, command: Command) -> Result<(), Error> {
+        match command {
+            Command::AddObstacle(obstacle) => self
+                .world
+                .run(|mut obstacles: UniqueViewMut<Obstacles>| obstacles.add(obstacle)),
+            Command::RemoveObstacleAt(point) => {
+                self.world.run(|mut obstacles: UniqueViewMut<Obstacles>| {
+                    let _ = obstacles.remove_at(Vec2::from(point));
+                })
+            }
+            Command::ClearObstacles => self
+                .world
+                .run(|mut obstacles: UniqueViewMut<Obstacles>| obstacles.clear()),
+            Command::Startle(point) => self.startle(Vec2::from(point)),
+            Command::TakeSnapshot => self.pending_snapshot = Some(Box::new(self.snapshot())),
+            Command::Restore(snapshot) => return self.restore(&snapshot),
+        }
+        Ok(())
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot::capture(&self.world)
+    }
+
+    pub fn restore(&mut self, snapshot: &Snapshot) -> Result<(), Error> {
+        snapshot.validate()?;
 
-        let mut new_cfg: Config = Config::default();
+        let cfg: Config = Config {
+            mouse_pos: self.config.mouse_pos,
+            mouse_vel: self.config.mouse_vel,
+            mouse_speed: self.config.mouse_speed,
+            running: self.config.running,
+            paused: self.config.paused,
+            ..snapshot.config
+        };
 
+        self.world.clear();
         self.world.run(
-            |positions: View<Position>,
-             velocities: View<Velocity>,
-             speeds: View<Speed>,
-             densities: View<Density>| {
-                new_cfg = io(SimulatorOutput::build(
-                    positions, velocities, speeds, densities,
-                ));
+            |mut world_cfg: UniqueViewMut<Config>,
+             mut sim_rng: UniqueViewMut<SimRng>,
+             mut chunks: UniqueViewMut<Chunks>,
+             mut captures: UniqueViewMut<Captures>,
+             mut obstacles: UniqueViewMut<Obstacles>,
+             mut wave: UniqueViewMut<AlarmWave>| {
+                *world_cfg = cfg;
+                *sim_rng = snapshot.rng;
+                *chunks = Chunks::new(&cfg);
+                let _ = captures.take();
+                *wave = AlarmWave::default();
+                obstacles.clear();
+                for obstacle in &snapshot.obstacles {
+                    obstacles.add(obstacle.clone());
+                }
             },
         );
+        snapshot.spawn(&mut self.world);
 
-        if self.world.run(|cfg: UniqueView<Config>| *cfg != new_cfg) {
-            self.update_config(new_cfg);
-        }
+        self.config = cfg;
+        Ok(())
+    }
 
-        self.paused = new_cfg.paused;
+    pub fn clock_mode(&self) -> ClockMode {
+        self.world
+            .run(|delta_time: UniqueView<DeltaTime>| delta_time.mode())
+    }
 
+    pub fn set_clock_mode(&mut self, mode: ClockMode) -> Result<(), Error> {
+        mode.validate()?;
+        self.world
+            .run(|mut delta_time: UniqueViewMut<DeltaTime>| delta_time.set_mode(mode));
         Ok(())
     }
 
-    pub fn update_config(&mut self, new_cfg: Config) {
+    pub fn update_config(&mut self, new_cfg: Config) -> Result<(), Error> {
+        new_cfg.validate()?;
+        self.config = new_cfg;
+        self.sync_config()
+    }
+
+    fn sync_config(&mut self) -> Result<(), Error> {
+        let new_cfg: Config = self.config;
+        if self.world.run(|cfg: UniqueView<Config>| *cfg == new_cfg) {
+            return Ok(());
+        }
+
+        new_cfg.validate()?;
+
         let old_cfg: Config = self
             .world
             .run(|mut cfg: UniqueViewMut<Config>| mem::replace(&mut *cfg, new_cfg));
 
-        match new_cfg.entity_count.cmp(&old_cfg.entity_count) {
+        for (index, (new, old)) in new_cfg.species.iter().zip(&old_cfg.species).enumerate() {
+            match new.count.cmp(&old.count) {
+                Ordering::Greater => {
+                    let to_add: usize = new.count - old.count;
+                    Fish::add(&mut self.world, index, to_add, new_cfg);
+                }
+                Ordering::Less => {
+                    let to_remove: usize = old.count - new.count;
+                    Fish::remove(&mut self.world, index, to_remove);
+                }
+                _ => (),
+            }
+        }
+
+        match new_cfg.predator_count.cmp(&old_cfg.predator_count) {
             Ordering::Greater => {
-                let to_add: usize = new_cfg.entity_count - old_cfg.entity_count;
-                Fish::add(&mut self.world, to_add, new_cfg);
+                let to_add: usize = new_cfg.predator_count - old_cfg.predator_count;
+                Predator::add(&mut self.world, to_add, new_cfg);
             }
             Ordering::Less => {
-                let to_remove: usize = old_cfg.entity_count - new_cfg.entity_count;
-                Fish::remove(&mut self.world, to_remove);
+                let to_remove: usize = old_cfg.predator_count - new_cfg.predator_count;
+                Predator::remove(&mut self.world, to_remove);
             }
             _ => (),
         }
+
+        Ok(())
+    }
+
+    fn startle(&mut self, point: Vec2) {
+        self.world.run(
+            |positions: View<Position>,
+             fish: View<FishIdentifier>,
+             mut stress: ViewMut<Stress>,
+             cfg: UniqueView<Config>| {
+                let boundary: Boundary = Boundary::from_config(&cfg);
+                let nearest: Option<EntityId> = (&positions, &fish)
+                    .iter()
+                    .with_id()
+                    .map(|(id, (pos, _))| (id, boundary.distance(pos.0, point)))
+                    .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                    .map(|(id, _)| id);
+
+                if let Some(mut stress) = nearest.and_then(|id| (&mut stress).get(id).ok()) {
+                    stress.0.value = 1.0;
+                }
+            },
+        );
+    }
+
+    fn run_workload(&mut self) -> Result<(), Error> {
+        self.world
+            .run_workload("sim")
+            .map_err(|err: RunWorkload| Error::Run(err.to_string()))?;
+
+        let captured: Vec<EntityId> = self
+            .world
+            .run(|mut captures: UniqueViewMut<Captures>| captures.take());
+        if captured.is_empty() {
+            return Ok(());
+        }
+
+        let captured_species: Vec<usize> = self.world.run(|species: View<Species>| {
+            captured
+                .iter()
+                .filter_map(|&id| species.get(id).ok().map(|species| species.0))
+                .collect()
+        });
+        for id in captured {
+            let _ = self.world.delete_entity(id);
+        }
+
+        for species in captured_species {
+            self.captured[species] += 1;
+            self.config.species[species].count =
+                self.config.species[species].count.saturating_sub(1);
+        }
+        let cfg: Config = self.config;
+        self.world
+            .run(|mut world_cfg: UniqueViewMut<Config>| *world_cfg = cfg);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FishShoalSimulator;
+    use crate::{
+        BehaviorModelKind, ClockMode, Command, Config, FishState, Metrics, Obstacle, Scalar,
+        SimulatorOutput, Snapshot, SpeciesInteraction, Speed, StressMode, TraitDistribution,
+        TraitParams, Traits, Vec2,
+    };
+    use shipyard::{IntoIter, ViewMut};
+
+    fn output(sim: &FishShoalSimulator) -> SimulatorOutput {
+        let mut output: SimulatorOutput = SimulatorOutput::default();
+        sim.output(&mut output);
+        output
+    }
+
+    #[test]
+    fn simulator_same_seed_same_population() {
+        let sim_a: FishShoalSimulator = FishShoalSimulator::with_seed(Some(42)).unwrap();
+        let sim_b: FishShoalSimulator = FishShoalSimulator::with_seed(Some(42)).unwrap();
+
+        assert_eq!(sim_a.seed(), 42);
+        assert_eq!(output(&sim_a).positions, output(&sim_b).positions);
+        assert_eq!(output(&sim_a).velocities, output(&sim_b).velocities);
+    }
+
+    #[test]
+    fn simulator_different_seed_different_population() {
+        let sim_a: FishShoalSimulator = FishShoalSimulator::with_seed(Some(1)).unwrap();
+        let sim_b: FishShoalSimulator = FishShoalSimulator::with_seed(Some(2)).unwrap();
+
+        assert_ne!(output(&sim_a).positions, output(&sim_b).positions);
+    }
+
+    #[test]
+    fn simulator_same_seed_same_trajectories() {
+        let mut sim_a: FishShoalSimulator = FishShoalSimulator::with_seed(Some(42)).unwrap();
+        let mut sim_b: FishShoalSimulator = FishShoalSimulator::with_seed(Some(42)).unwrap();
+
+        sim_a.step_n(60, 1.0 / 60.0).unwrap();
+        sim_b.step_n(60, 1.0 / 60.0).unwrap();
+
+        let output_a: SimulatorOutput = output(&sim_a);
+        let output_b: SimulatorOutput = output(&sim_b);
+
+        assert_eq!(output_a.positions, output_b.positions);
+        assert_eq!(output_a.velocities, output_b.velocities);
+        assert_eq!(output_a.speeds, output_b.speeds);
+    }
+
+    #[test]
+    fn simulator_thread_count_same_trajectories() {
+        let step = |threads: usize| -> SimulatorOutput {
+            let pool: rayon::ThreadPool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            let mut sim: FishShoalSimulator = FishShoalSimulator::with_seed(Some(42)).unwrap();
+
+            pool.install(|| sim.step_n(60, 1.0 / 60.0)).unwrap();
+            output(&sim)
+        };
+
+        let output_a: SimulatorOutput = step(1);
+        let output_b: SimulatorOutput = step(4);
+
+        assert_eq!(output_a.positions, output_b.positions);
+        assert_eq!(output_a.velocities, output_b.velocities);
+        assert_eq!(output_a.speeds, output_b.speeds);
+    }
+
+    #[test]
+    fn simulator_behavior_models_same_trajectories() {
+        for model in [
+            BehaviorModelKind::Zonal,
+            BehaviorModelKind::Vicsek,
+            BehaviorModelKind::Couzin,
+            BehaviorModelKind::Reynolds,
+        ] {
+            let cfg: Config = Config {
+                behavior_model: model,
+                ..Config::default()
+            };
+            let mut sim_a: FishShoalSimulator =
+                FishShoalSimulator::with_config(cfg, Some(42)).unwrap();
+            let mut sim_b: FishShoalSimulator =
+                FishShoalSimulator::with_config(cfg, Some(42)).unwrap();
+            let before: SimulatorOutput = output(&sim_a);
+
+            sim_a.step_n(30, 1.0 / 60.0).unwrap();
+            sim_b.step_n(30, 1.0 / 60.0).unwrap();
+
+            let output_a: SimulatorOutput = output(&sim_a);
+            assert_ne!(before.velocities, output_a.velocities, "{model:?}");
+            assert_eq!(output_a.positions, output(&sim_b).positions, "{model:?}");
+            assert_eq!(output_a.velocities, output(&sim_b).velocities, "{model:?}");
+        }
+    }
+
+    #[test]
+    fn simulator_trait_variation() {
+        let mut cfg: Config = Config::default();
+        cfg.species[0].traits = TraitParams {
+            speed: TraitDistribution {
+                mean: 1.0,
+                std_dev: 0.2,
+            },
+            perception: TraitDistribution {
+                mean: 1.0,
+                std_dev: 0.2,
+            },
+            ..TraitParams::default()
+        };
+        let mut sim_a: FishShoalSimulator = FishShoalSimulator::with_config(cfg, Some(42)).unwrap();
+        let mut sim_b: FishShoalSimulator = FishShoalSimulator::with_config(cfg, Some(42)).unwrap();
+        let mut sim_c: FishShoalSimulator =
+            FishShoalSimulator::with_config(Config::default(), Some(42)).unwrap();
+
+        let traits: Vec<Traits> = sim_a
+            .snapshot()
+            .fish
+            .iter()
+            .map(|fish| fish.traits)
+            .collect();
+        assert!(traits.windows(2).any(|pair| pair[0].speed != pair[1].speed));
+        assert!(traits.iter().all(|traits| traits.body_length == 10.0));
+        assert_eq!(sim_a.snapshot().fish, sim_b.snapshot().fish);
+
+        let defaults: Vec<Traits> = sim_c
+            .snapshot()
+            .fish
+            .iter()
+            .map(|fish| fish.traits)
+            .collect();
+        assert!(defaults.iter().all(|traits| *traits == Traits::default()));
+
+        sim_a.step_n(30, 1.0 / 60.0).unwrap();
+        sim_b.step_n(30, 1.0 / 60.0).unwrap();
+        sim_c.step_n(30, 1.0 / 60.0).unwrap();
+
+        assert_eq!(output(&sim_a).positions, output(&sim_b).positions);
+        assert_ne!(output(&sim_a).positions, output(&sim_c).positions);
+    }
+
+    #[test]
+    fn simulator_species_populations() {
+        let mut cfg: Config = Config::default();
+        cfg.species[0].count = 30;
+        cfg.species[2].count = 12;
+        let mut sim: FishShoalSimulator = FishShoalSimulator::with_config(cfg, Some(6)).unwrap();
+        assert_eq!(output(&sim).species_counts(), [30, 0, 12, 0]);
+
+        cfg.species[0].count = 20;
+        cfg.species[1].count = 5;
+        sim.update_config(cfg).unwrap();
+        assert_eq!(output(&sim).species_counts(), [20, 5, 12, 0]);
+        assert_eq!(sim.fish_count(), cfg.fish_count());
+    }
+
+    #[test]
+    fn simulator_informed_fish_lead_the_shoal() {
+        let mut cfg: Config = Config {
+            informed_fraction: 0.2,
+            goal_weight: 1.0,
+            ..Config::default()
+        };
+        cfg.species[0].count = 200;
+        let mut sim: FishShoalSimulator = FishShoalSimulator::with_config(cfg, Some(12)).unwrap();
+
+        let informed: usize = output(&sim).metrics.informed_count;
+        assert!((20..=60).contains(&informed));
+
+        for _ in 0..600 {
+            sim.step(1.0 / 60.0).unwrap();
+        }
+        let metrics: Metrics = output(&sim).metrics;
+        assert!(metrics.goal_accuracy[0] > 0.5);
+        assert_eq!(metrics.goal_accuracy[1], 0.0);
+    }
+
+    #[test]
+    fn simulator_species_interactions() {
+        let react = |rule: SpeciesInteraction| -> FishState {
+            let mut cfg: Config = Config::default();
+            cfg.species[0].count = 1;
+            cfg.species[1].count = 1;
+            cfg.species[0].interactions[1] = rule;
+            let mut sim: FishShoalSimulator =
+                FishShoalSimulator::with_config(cfg, Some(8)).unwrap();
+
+            let mut snapshot: Snapshot = sim.snapshot();
+            for fish in &mut snapshot.fish {
+                let x: f32 = if fish.species == 0 { 100.0 } else { 120.0 };
+                fish.position = Vec2::new(x, 100.0);
+                fish.velocity = Vec2::new(0.0, 1.0);
+                fish.target_velocity = Vec2::new(0.0, 1.0);
+            }
+            sim.restore(&snapshot).unwrap();
+            sim.step(0.001).unwrap();
+
+            sim.snapshot()
+                .fish
+                .into_iter()
+                .find(|fish| fish.species == 0)
+                .unwrap()
+        };
+
+        assert!(react(SpeciesInteraction::Align).in_group);
+        assert!(!react(SpeciesInteraction::Ignore).in_group);
+        assert!(react(SpeciesInteraction::Attract).target_velocity.x > 0.5);
+        assert!(react(SpeciesInteraction::Avoid).target_velocity.x < -0.5);
+    }
+
+    #[test]
+    fn simulator_alarm_wave_spreads_and_fades() {
+        let wave = |probability: f64| -> Metrics {
+            let mut cfg: Config = Config {
+                stress_mode: StressMode::Contagion,
+                ..Config::default()
+            };
+            cfg.species[0].count = 10;
+            cfg.stress.transmission_probability = probability;
+            let mut sim: FishShoalSimulator =
+                FishShoalSimulator::with_config(cfg, Some(4)).unwrap();
+
+            let mut snapshot: Snapshot = sim.snapshot();
+            for (idx, fish) in snapshot.fish.iter_mut().enumerate() {
+                fish.position = Vec2::new(100.0 + 12.0 * idx as f32, 100.0);
+                fish.stress = cfg.stress.baseline;
+            }
+            sim.restore(&snapshot).unwrap();
+            sim.execute(Command::Startle([100.0, 100.0])).unwrap();
+            sim.step_n(60, 0.05).unwrap();
+
+            let spread: Metrics = output(&sim).metrics;
+            sim.step_n(200, 0.05).unwrap();
+
+            let faded: SimulatorOutput = output(&sim);
+            assert_eq!(faded.metrics.alarmed_count, 0);
+            assert!(faded
+                .stresses
+                .iter()
+                .all(|&stress| stress < cfg.stress.alarm_threshold));
+            spread
+        };
+
+        let spread: Metrics = wave(1.0);
+        assert_eq!(spread.wave_reach, 10);
+        assert!(spread.wave_distance > 80.0);
+        assert!(spread.wave_speed > 50.0);
+
+        let isolated: Metrics = wave(0.0);
+        assert_eq!(isolated.wave_reach, 1);
+        assert_eq!(isolated.wave_speed, 0.0);
+    }
+
+    #[test]
+    fn simulator_step_moves_fish() {
+        let mut sim: FishShoalSimulator = FishShoalSimulator::with_seed(Some(7)).unwrap();
+        let before: SimulatorOutput = output(&sim);
+
+        sim.step_n(10, 0.1).unwrap();
+
+        assert_ne!(before.positions, output(&sim).positions);
+    }
+
+    #[test]
+    fn simulator_predator_captures_fish() {
+        let mut cfg: Config = Config {
+            predator_count: 1,
+            predator_vision_radius: 10_000.0,
+            predator_capture_radius: 10_000.0,
+            predator_attack_cooldown: 0.0,
+            ..Config::default()
+        };
+        cfg.species[0].count = 20;
+        cfg.behavior.sated_hunger = 0.0;
+        let mut sim: FishShoalSimulator = FishShoalSimulator::with_config(cfg, Some(3)).unwrap();
+
+        assert_eq!(output(&sim).predator_ids.len(), 1);
+
+        sim.step_n(5, 0.1).unwrap();
+
+        assert_eq!(output(&sim).len(), 15);
+        assert_eq!(sim.config().species[0].count, 15);
+        assert_eq!(sim.snapshot().config.species[0].count, 15);
+
+        let mut cfg: Config = *sim.config();
+        cfg.predator_count = 0;
+        cfg.species[0].count = 10;
+        sim.update_config(cfg).unwrap();
+        assert_eq!(output(&sim).len(), 10);
+    }
+
+    #[test]
+    fn simulator_predator_count_follows_config() {
+        let mut sim: FishShoalSimulator = FishShoalSimulator::with_seed(Some(3)).unwrap();
+        assert!(output(&sim).predator_ids.is_empty());
+
+        let mut cfg: Config = Config {
+            predator_count: 3,
+            ..Config::default()
+        };
+        sim.update_config(cfg).unwrap();
+        assert_eq!(output(&sim).predator_ids.len(), 3);
+
+        cfg.predator_count = 1;
+        sim.update_config(cfg).unwrap();
+        assert_eq!(output(&sim).predator_ids.len(), 1);
+    }
+
+    #[test]
+    fn simulator_fish_stay_out_of_obstacles() {
+        let mut sim: FishShoalSimulator = FishShoalSimulator::with_seed(Some(11)).unwrap();
+        let obstacle: Obstacle = Obstacle::circle(Vec2::new(960.0, 540.0), 300.0);
+        let edge: Obstacle = Obstacle::rect(Vec2::new(-50.0, 0.0), Vec2::new(100.0, 1080.0));
+
+        sim.execute(Command::AddObstacle(obstacle.clone())).unwrap();
+        sim.execute(Command::AddObstacle(edge.clone())).unwrap();
+        for _ in 0..40 {
+            sim.step(0.05).unwrap();
+
+            let data: SimulatorOutput = output(&sim);
+            assert!(data.positions.iter().all(|&pos| {
+                !obstacle.contains(Vec2::from(pos)) && !edge.contains(Vec2::from(pos))
+            }));
+        }
+        assert_eq!(output(&sim).obstacles, vec![obstacle, edge.clone()]);
+
+        sim.execute(Command::RemoveObstacleAt([960.0, 540.0]))
+            .unwrap();
+        assert_eq!(output(&sim).obstacles, vec![edge]);
+    }
+
+    #[test]
+    fn simulator_builder_population_and_seed() {
+        let sim: FishShoalSimulator = FishShoalSimulator::builder()
+            .seed(5)
+            .population(25)
+            .build()
+            .unwrap();
+
+        assert_eq!(sim.seed(), 5);
+        assert_eq!(sim.fish_count(), 25);
+        assert_eq!(sim.config().species[0].count, 25);
+    }
+
+    #[test]
+    fn simulator_builder_rejects_invalid_config() {
+        let cfg: Config = Config {
+            width: 0,
+            ..Config::default()
+        };
+
+        assert!(FishShoalSimulator::builder().config(cfg).build().is_err());
+    }
+
+    #[test]
+    fn simulator_builder_extra_system() {
+        fn freeze(mut speeds: ViewMut<Speed>) {
+            (&mut speeds)
+                .iter()
+                .for_each(|speed| speed.0 = Scalar::ZERO);
+        }
+
+        let mut sim: FishShoalSimulator = FishShoalSimulator::builder()
+            .seed(9)
+            .system(freeze)
+            .build()
+            .unwrap();
+
+        sim.step(0.1).unwrap();
+        let frozen: SimulatorOutput = output(&sim);
+        sim.step_n(5, 0.1).unwrap();
+
+        assert_eq!(frozen.positions, output(&sim).positions);
+    }
+
+    #[test]
+    fn simulator_config_mut_applies_on_step() {
+        let mut sim: FishShoalSimulator = FishShoalSimulator::with_seed(Some(4)).unwrap();
+
+        sim.config_mut().species[0].count = 10;
+        assert_eq!(sim.fish_count(), 500);
+
+        sim.step(0.1).unwrap();
+        assert_eq!(sim.fish_count(), 10);
+
+        sim.config_mut().avoidance_radius = 1_000.0;
+        assert!(sim.step(0.1).is_err());
+    }
+
+    #[test]
+    fn simulator_rejects_non_finite_time() {
+        let mut sim: FishShoalSimulator = FishShoalSimulator::with_seed(Some(4)).unwrap();
+
+        assert!(sim.step(f32::INFINITY).is_err());
+        assert!(sim.step_n(3, f32::NAN).is_err());
+        assert!(sim
+            .set_clock_mode(ClockMode::Scaled(f32::INFINITY))
+            .is_err());
+        assert!(sim
+            .set_clock_mode(ClockMode::Fixed {
+                step: f32::NAN,
+                max_substeps: 4,
+            })
+            .is_err());
+        assert_eq!(sim.clock_mode(), ClockMode::RealTime);
+
+        assert!(sim.set_clock_mode(ClockMode::Scaled(2.0)).is_ok());
+        assert_eq!(sim.clock_mode(), ClockMode::Scaled(2.0));
+        assert!(sim.step(1.0 / 60.0).is_ok());
     }
 }
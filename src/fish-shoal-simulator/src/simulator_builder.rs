@@ -0,0 +1,128 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    entities::{Fish, Predator},
+    systems::*,
+    AlarmWave, Captures, Chunks, Config, DeltaTime, Error, FishShoalSimulator, Obstacles, SimRng,
+};
+use shipyard::{error::AddWorkload, IntoWorkloadSystem, Workload, World};
+use std::fmt::{self, Debug, Formatter};
+
+type ExtraSystem = Box<dyn FnOnce(Workload) -> Workload>;
+
+#[derive(Default)]
+pub struct FishShoalSimulatorBuilder {
+    config: Config,
+    seed: Option<u64>,
+    population: Option<usize>,
+    systems: Vec<ExtraSystem>,
+}
+
+impl FishShoalSimulatorBuilder {
+    pub fn config(mut self, cfg: Config) -> Self {
+        self.config = cfg;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn maybe_seed(mut self, seed: Option<u64>) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn population(mut self, count: usize) -> Self {
+        self.population = Some(count);
+        self
+    }
+
+    pub fn system<B, R, S>(mut self, system: S) -> Self
+    where
+        B: 'static,
+        R: 'static,
+        S: IntoWorkloadSystem<B, R> + 'static,
+    {
+        self.systems.push(Box::new(move |workload: Workload| {
+            workload.with_system(system)
+        }));
+        self
+    }
+
+    pub fn build(self) -> Result<FishShoalSimulator, Error> {
+        let mut cfg: Config = self.config;
+        if let Some(count) = self.population {
+            cfg.species[0].count = count;
+        }
+        cfg.validate()?;
+
+        let mut world: World = World::default();
+
+        world.add_unique(cfg);
+        world.add_unique(DeltaTime::default());
+        world.add_unique(SimRng::new(self.seed));
+        world.add_unique(Chunks::new(&cfg));
+        world.add_unique(Captures::default());
+        world.add_unique(Obstacles::default());
+        world.add_unique(AlarmWave::default());
+
+        for (index, species) in cfg.species.iter().enumerate() {
+            Fish::add(&mut world, index, species.count, cfg);
+        }
+        Predator::add(&mut world, cfg.predator_count, cfg);
+
+        let workload: Workload = Workload::new("sim")
+            .with_system(AdvanceRng::system)
+            .with_system(LoadChunks::system)
+            .with_barrier()
+            .with_system(Motion::system)
+            .with_system(OutOfBound::system)
+            .with_system(ObstacleCollision::system)
+            .with_system(LerpToTarget::system)
+            .with_system(SteerToTarget::system)
+            .with_system(RandomBehavior::system)
+            .with_system(Swarming::system)
+            .with_system(Navigation::system)
+            .with_system(MouseInteraction::system)
+            .with_system(Escape::system)
+            .with_system(Hunting::system)
+            .with_system(BoundarySteering::system)
+            .with_system(ObstacleAvoidance::system)
+            .with_system(StressContagion::system);
+
+        self.systems
+            .into_iter()
+            .fold(workload, |workload, add_system| add_system(workload))
+            .add_to_world(&world)
+            .map_err(|err: AddWorkload| Error::Create(err.to_string()))?;
+
+        Ok(FishShoalSimulator::from_world(world, cfg))
+    }
+}
+
+impl Debug for FishShoalSimulatorBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FishShoalSimulatorBuilder")
+            .field("config", &self.config)
+            .field("seed", &self.seed)
+            .field("population", &self.population)
+            .field("systems", &self.systems.len())
+            .finish()
+    }
+}
@@ -14,48 +14,153 @@
  * limitations under the License.
  */
 
-use crate::{Density, Position, Speed, Velocity};
-use shipyard::{IntoIter, View};
+use crate::{
+    Alarm, AlarmWave, Boundary, Config, Density, GoalRank, Metrics, Obstacle, Obstacles, Position,
+    PredatorIdentifier, Snapshot, Social, Species, Speed, Stress, Velocity, MAX_SPECIES,
+};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use shipyard::{IntoIter, UniqueView, View, World};
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct SimulatorOutput {
     pub ids: Vec<usize>,
     pub positions: Vec<[f32; 2]>,
     pub velocities: Vec<[f32; 2]>,
     pub speeds: Vec<f32>,
     pub densities: Vec<usize>,
+    pub stresses: Vec<f32>,
+    pub in_groups: Vec<bool>,
+    pub species: Vec<usize>,
+    pub goals: Vec<Option<usize>>,
+    pub predator_ids: Vec<usize>,
+    pub predator_positions: Vec<[f32; 2]>,
+    pub predator_velocities: Vec<[f32; 2]>,
+    pub obstacles: Vec<Obstacle>,
+    pub metrics: Metrics,
+    pub captured: [usize; MAX_SPECIES],
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub snapshot: Option<Box<Snapshot>>,
 }
 
 impl SimulatorOutput {
-    pub(crate) fn build(
-        position_view: View<Position>,
-        velocity_view: View<Velocity>,
-        speed_view: View<Speed>,
-        density_view: View<Density>,
-    ) -> Self {
-        let mut ids: Vec<usize> = Vec::new();
-        let mut positions: Vec<[f32; 2]> = Vec::new();
-        let mut velocities: Vec<[f32; 2]> = Vec::new();
-        let mut speeds: Vec<f32> = Vec::new();
-        let mut densities: Vec<usize> = Vec::new();
-
-        (&position_view, &velocity_view, &speed_view, &density_view)
-            .iter()
-            .with_id()
-            .for_each(|(id, (pos, vel, speed, density))| {
-                ids.push(id.uindex());
-                positions.push(pos.0.into());
-                velocities.push(vel.0.into());
-                speeds.push(speed.0.into());
-                densities.push(density.value);
-            });
-
-        Self {
-            ids,
-            positions,
-            velocities,
-            speeds,
-            densities,
+    pub(crate) fn build(world: &World) -> Self {
+        let mut output: Self = Self::default();
+        output.fill(world);
+        output
+    }
+
+    pub(crate) fn fill(&mut self, world: &World) {
+        self.clear();
+
+        world.run(
+            |position_view: View<Position>,
+             velocity_view: View<Velocity>,
+             speed_view: View<Speed>,
+             density_view: View<Density>,
+             stress_view: View<Stress>,
+             social_view: View<Social>,
+             species_view: View<Species>,
+             goal_rank_view: View<GoalRank>,
+             predator_view: View<PredatorIdentifier>,
+             alarm_view: View<Alarm>,
+             obstacles: UniqueView<Obstacles>,
+             wave: UniqueView<AlarmWave>,
+             cfg: UniqueView<Config>| {
+                (
+                    &position_view,
+                    &velocity_view,
+                    &speed_view,
+                    &density_view,
+                    &stress_view,
+                    &social_view,
+                    &species_view,
+                    &goal_rank_view,
+                )
+                    .iter()
+                    .with_id()
+                    .for_each(
+                        |(id, (pos, vel, speed, density, stress, social, species, goal_rank))| {
+                            self.ids.push(id.uindex());
+                            self.positions.push(pos.0.into());
+                            self.velocities.push(vel.0.into());
+                            self.speeds.push(speed.0.into());
+                            self.densities.push(density.value);
+                            self.stresses.push(stress.0.into());
+                            self.in_groups.push(social.is_in_group);
+                            self.species.push(species.0);
+                            self.goals.push(cfg.goal(goal_rank.0));
+                        },
+                    );
+
+                (&position_view, &velocity_view, &predator_view)
+                    .iter()
+                    .with_id()
+                    .for_each(|(id, (pos, vel, _))| {
+                        self.predator_ids.push(id.uindex());
+                        self.predator_positions.push(pos.0.into());
+                        self.predator_velocities.push(vel.0.into());
+                    });
+
+                self.obstacles.extend_from_slice(obstacles.as_slice());
+
+                let boundary: Boundary = Boundary::from_config(&cfg);
+                self.metrics = Metrics::compute(
+                    &self.positions,
+                    &self.velocities,
+                    &self.speeds,
+                    boundary,
+                    cfg.attraction_radius,
+                );
+                self.metrics.informed_count = self.goals.iter().flatten().count();
+                self.metrics.goal_accuracy = Metrics::goal_accuracy(
+                    &self.positions,
+                    &self.velocities,
+                    &self.goals,
+                    &cfg.goals,
+                    boundary,
+                );
+                self.metrics.alarmed_count =
+                    alarm_view.iter().filter(|alarm| alarm.is_alarmed).count();
+                self.metrics.wave_reach = wave.reached();
+                self.metrics.wave_distance = wave.distance();
+                self.metrics.wave_speed = wave.speed();
+            },
+        );
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn species_counts(&self) -> [usize; MAX_SPECIES] {
+        let mut counts: [usize; MAX_SPECIES] = [0; MAX_SPECIES];
+        for &species in &self.species {
+            counts[species] += 1;
         }
+        counts
+    }
+
+    fn clear(&mut self) {
+        self.ids.clear();
+        self.positions.clear();
+        self.velocities.clear();
+        self.speeds.clear();
+        self.densities.clear();
+        self.stresses.clear();
+        self.in_groups.clear();
+        self.species.clear();
+        self.goals.clear();
+        self.predator_ids.clear();
+        self.predator_positions.clear();
+        self.predator_velocities.clear();
+        self.obstacles.clear();
+        self.captured = [0; MAX_SPECIES];
+        self.snapshot = None;
     }
 }
@@ -0,0 +1,515 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    Alarm, AttackCooldown, Config, Density, Error, FishIdentifier, GoalRank, Hunger, Obstacle,
+    Obstacles, Position, PredatorIdentifier, PredatorTarget, RngKey, SafePosition, Scalar, SimRng,
+    Social, Species, Speed, Stress, TargetSpeed, TargetVelocity, Traits, Vec2, Velocity,
+    MAX_SPECIES,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde_json::Value;
+use shipyard::{EntityId, IntoIter, UniqueView, View, World};
+#[cfg(feature = "serde")]
+use std::{fs, path::Path};
+
+pub const SNAPSHOT_VERSION: u64 = 2;
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct SnapshotHeader {
+    version: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Snapshot {
+    pub version: u64,
+    pub config: Config,
+    pub rng: SimRng,
+    pub fish: Vec<FishState>,
+    pub predators: Vec<PredatorState>,
+    pub obstacles: Vec<Obstacle>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FishState {
+    pub key: u64,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub target_velocity: Vec2,
+    pub speed: f32,
+    pub target_speed: f32,
+    pub stress: f32,
+    pub density: usize,
+    pub in_group: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub traits: Traits,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub species: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub goal_rank: GoalRank,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub alarm: Alarm,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PredatorState {
+    pub key: u64,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub target_velocity: Vec2,
+    pub speed: f32,
+    pub target_speed: f32,
+    pub stress: f32,
+    pub hunger: f32,
+    pub cooldown: f32,
+    pub target: Option<usize>,
+}
+
+impl Snapshot {
+    pub(crate) fn capture(world: &World) -> Self {
+        world.run(
+            |fish_view: View<FishIdentifier>,
+             predator_view: View<PredatorIdentifier>,
+             key_view: View<RngKey>,
+             position_view: View<Position>,
+             velocity_view: View<Velocity>,
+             target_velocity_view: View<TargetVelocity>,
+             speed_view: View<Speed>,
+             target_speed_view: View<TargetSpeed>,
+             stress_view: View<Stress>,
+             density_view: View<Density>,
+             social_view: View<Social>,
+             traits_view: View<Traits>,
+             species_view: View<Species>,
+             goal_rank_view: View<GoalRank>,
+             alarm_view: View<Alarm>,
+             hunger_view: View<Hunger>,
+             cooldown_view: View<AttackCooldown>,
+             target_view: View<PredatorTarget>,
+             cfg: UniqueView<Config>,
+             sim_rng: UniqueView<SimRng>,
+             obstacles: UniqueView<Obstacles>| {
+                let mut fish: Vec<(EntityId, FishState)> = (
+                    &fish_view,
+                    &key_view,
+                    &position_view,
+                    &velocity_view,
+                    &target_velocity_view,
+                    &speed_view,
+                    &target_speed_view,
+                    &stress_view,
+                    &density_view,
+                    &social_view,
+                    &traits_view,
+                    &species_view,
+                    &goal_rank_view,
+                    &alarm_view,
+                )
+                    .iter()
+                    .with_id()
+                    .map(
+                        |(
+                            id,
+                            (
+                                _,
+                                key,
+                                pos,
+                                vel,
+                                target_vel,
+                                speed,
+                                target_speed,
+                                stress,
+                                density,
+                                social,
+                                traits,
+                                species,
+                                goal_rank,
+                                alarm,
+                            ),
+                        )| {
+                            (
+                                id,
+                                FishState {
+                                    key: key.0,
+                                    position: pos.0,
+                                    velocity: vel.0,
+                                    target_velocity: target_vel.0,
+                                    speed: speed.0.into(),
+                                    target_speed: target_speed.0.into(),
+                                    stress: stress.0.into(),
+                                    density: density.value,
+                                    in_group: social.is_in_group,
+                                    traits: *traits,
+                                    species: species.0,
+                                    goal_rank: *goal_rank,
+                                    alarm: *alarm,
+                                },
+                            )
+                        },
+                    )
+                    .collect();
+                fish.sort_by_key(|&(id, _)| id);
+
+                let mut predators: Vec<(EntityId, PredatorState)> = (
+                    &predator_view,
+                    &key_view,
+                    &position_view,
+                    &velocity_view,
+                    &target_velocity_view,
+                    &speed_view,
+                    &target_speed_view,
+                    &stress_view,
+                    &hunger_view,
+                    &cooldown_view,
+                    &target_view,
+                )
+                    .iter()
+                    .with_id()
+                    .map(
+                        |(
+                            id,
+                            (
+                                _,
+                                key,
+                                pos,
+                                vel,
+                                target_vel,
+                                speed,
+                                target_speed,
+                                stress,
+                                hunger,
+                                cooldown,
+                                target,
+                            ),
+                        )| {
+                            (
+                                id,
+                                PredatorState {
+                                    key: key.0,
+                                    position: pos.0,
+                                    velocity: vel.0,
+                                    target_velocity: target_vel.0,
+                                    speed: speed.0.into(),
+                                    target_speed: target_speed.0.into(),
+                                    stress: stress.0.into(),
+                                    hunger: hunger.0.into(),
+                                    cooldown: cooldown.remaining,
+                                    target: target.0.and_then(|prey| {
+                                        fish.binary_search_by_key(&prey, |&(id, _)| id).ok()
+                                    }),
+                                },
+                            )
+                        },
+                    )
+                    .collect();
+                predators.sort_by_key(|&(id, _)| id);
+
+                Self {
+                    version: SNAPSHOT_VERSION,
+                    config: *cfg,
+                    rng: *sim_rng,
+                    fish: fish.into_iter().map(|(_, state)| state).collect(),
+                    predators: predators.into_iter().map(|(_, state)| state).collect(),
+                    obstacles: obstacles.as_slice().to_vec(),
+                }
+            },
+        )
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.version != SNAPSHOT_VERSION {
+            return Err(Error::Snapshot(format!(
+                "version {} is not supported, expected {SNAPSHOT_VERSION}",
+                self.version
+            )));
+        }
+
+        if let Some(species) = self
+            .fish
+            .iter()
+            .map(|fish| fish.species)
+            .find(|&species| species >= MAX_SPECIES)
+        {
+            return Err(Error::Snapshot(format!(
+                "fish species {species} is out of range for {MAX_SPECIES} species"
+            )));
+        }
+
+        if let Some(target) = self
+            .predators
+            .iter()
+            .filter_map(|predator| predator.target)
+            .find(|&target| target >= self.fish.len())
+        {
+            return Err(Error::Snapshot(format!(
+                "predator target {target} is out of range for {} fish",
+                self.fish.len()
+            )));
+        }
+
+        self.config.validate()
+    }
+
+    pub(crate) fn spawn(&self, world: &mut World) {
+        let fish_ids: Vec<EntityId> = self
+            .fish
+            .iter()
+            .map(|fish| {
+                world.add_entity((
+                    FishIdentifier,
+                    RngKey(fish.key),
+                    Position(fish.position),
+                    SafePosition(fish.position),
+                    Velocity(fish.velocity),
+                    TargetVelocity(fish.target_velocity),
+                    Speed(Scalar::new(fish.speed)),
+                    TargetSpeed(Scalar::new(fish.target_speed)),
+                    Stress(Scalar::new(fish.stress)),
+                    Density {
+                        value: fish.density,
+                    },
+                    Social {
+                        is_in_group: fish.in_group,
+                    },
+                    Species(fish.species),
+                    fish.traits,
+                    fish.goal_rank,
+                    fish.alarm,
+                ))
+            })
+            .collect();
+
+        for predator in &self.predators {
+            world.add_entity((
+                PredatorIdentifier,
+                RngKey(predator.key),
+                Position(predator.position),
+                SafePosition(predator.position),
+                Velocity(predator.velocity),
+                TargetVelocity(predator.target_velocity),
+                Speed(Scalar::new(predator.speed)),
+                TargetSpeed(Scalar::new(predator.target_speed)),
+                Stress(Scalar::new(predator.stress)),
+                Hunger(Scalar::new(predator.hunger)),
+                PredatorTarget(predator.target.map(|index| fish_ids[index])),
+                AttackCooldown {
+                    remaining: predator.cooldown,
+                },
+            ));
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Snapshot {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let text: String = fs::read_to_string(path)
+            .map_err(|err| Error::Snapshot(format!("{}: {err}", path.display())))?;
+
+        Self::parse(&text).map_err(|err| match err {
+            Error::Snapshot(msg) => Error::Snapshot(format!("{}: {msg}", path.display())),
+            other => other,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        fs::write(path, self.serialize()?)
+            .map_err(|err| Error::Snapshot(format!("{}: {err}", path.display())))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let header: SnapshotHeader =
+            serde_json::from_str(text).map_err(|err| Error::Snapshot(err.to_string()))?;
+        if header.version == 0 || header.version > SNAPSHOT_VERSION {
+            return Err(Error::Snapshot(format!(
+                "version {} is not supported, expected {SNAPSHOT_VERSION}",
+                header.version
+            )));
+        }
+
+        let mut value: Value =
+            serde_json::from_str(text).map_err(|err| Error::Snapshot(err.to_string()))?;
+        if header.version < SNAPSHOT_VERSION {
+            value = Self::upgrade(value)?;
+        }
+
+        let snapshot: Self =
+            serde_json::from_value(value).map_err(|err| Error::Snapshot(err.to_string()))?;
+
+        snapshot.validate()?;
+        Ok(snapshot)
+    }
+
+    pub fn serialize(&self) -> Result<String, Error> {
+        serde_json::to_string(self).map_err(|err| Error::Snapshot(err.to_string()))
+    }
+
+    // Version 1 snapshots embed a version 1 configuration, from before species
+    fn upgrade(value: Value) -> Result<Value, Error> {
+        let Value::Object(mut fields) = value else {
+            return Err(Error::Snapshot("expected an object".to_string()));
+        };
+        let Some(Value::Object(mut config)) = fields.remove("config") else {
+            return Err(Error::Snapshot("missing config".to_string()));
+        };
+
+        config.insert("version".to_string(), Value::from(1));
+        fields.insert(
+            "config".to_string(),
+            Config::migrate(Value::Object(config))?,
+        );
+        fields.insert("version".to_string(), Value::from(SNAPSHOT_VERSION));
+        Ok(Value::Object(fields))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Snapshot, SNAPSHOT_VERSION};
+    use crate::{Command, Config, Error, FishShoalSimulator, Obstacle, Vec2, MAX_SPECIES};
+    #[cfg(feature = "serde")]
+    use serde_json::{Map, Value};
+
+    fn running_sim() -> FishShoalSimulator {
+        let mut cfg: Config = Config {
+            predator_count: 2,
+            predator_vision_radius: 10_000.0,
+            ..Config::default()
+        };
+        cfg.species[0].count = 100;
+        cfg.species[1].count = 20;
+        let mut sim: FishShoalSimulator = FishShoalSimulator::with_config(cfg, Some(21)).unwrap();
+        sim.execute(Command::AddObstacle(Obstacle::circle(
+            Vec2::new(300.0, 300.0),
+            40.0,
+        )))
+        .unwrap();
+        sim.step_n(30, 1.0 / 30.0).unwrap();
+        sim
+    }
+
+    #[test]
+    fn snapshot_captures_world() {
+        let snapshot: Snapshot = running_sim().snapshot();
+
+        assert_eq!(snapshot.version, SNAPSHOT_VERSION);
+        assert_eq!(snapshot.config.fish_count(), 120);
+        assert_eq!(snapshot.rng.seed(), 21);
+        assert_eq!(snapshot.predators.len(), 2);
+        assert_eq!(snapshot.obstacles.len(), 1);
+        assert!(!snapshot.fish.is_empty());
+    }
+
+    #[test]
+    fn snapshot_restore_into_new_simulator() {
+        let mut sim_a: FishShoalSimulator = running_sim();
+        let snapshot: Snapshot = sim_a.snapshot();
+
+        let mut sim_b: FishShoalSimulator =
+            FishShoalSimulator::builder().population(0).build().unwrap();
+        sim_b.restore(&snapshot).unwrap();
+        assert_eq!(sim_b.snapshot(), snapshot);
+
+        sim_a.step_n(30, 1.0 / 30.0).unwrap();
+        sim_b.step_n(30, 1.0 / 30.0).unwrap();
+
+        assert_eq!(sim_a.snapshot(), sim_b.snapshot());
+    }
+
+    #[test]
+    fn snapshot_restore_in_place() {
+        let mut sim: FishShoalSimulator = running_sim();
+        let snapshot: Snapshot = sim.snapshot();
+
+        sim.step_n(30, 1.0 / 30.0).unwrap();
+        let first: Snapshot = sim.snapshot();
+
+        sim.restore(&snapshot).unwrap();
+        assert_eq!(sim.snapshot(), snapshot);
+
+        sim.step_n(30, 1.0 / 30.0).unwrap();
+        assert_eq!(sim.snapshot(), first);
+    }
+
+    #[test]
+    fn snapshot_rejects_unknown_species() {
+        let mut snapshot: Snapshot = running_sim().snapshot();
+        snapshot.fish[0].species = MAX_SPECIES;
+
+        let mut sim: FishShoalSimulator = FishShoalSimulator::new().unwrap();
+        assert!(matches!(sim.restore(&snapshot), Err(Error::Snapshot(_))));
+    }
+
+    #[test]
+    fn snapshot_rejects_invalid_target() {
+        let mut snapshot: Snapshot = running_sim().snapshot();
+        snapshot.predators[0].target = Some(snapshot.fish.len());
+
+        let mut sim: FishShoalSimulator = FishShoalSimulator::new().unwrap();
+        assert!(matches!(sim.restore(&snapshot), Err(Error::Snapshot(_))));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn snapshot_json_round_trip() {
+        let snapshot: Snapshot = running_sim().snapshot();
+        let text: String = snapshot.serialize().unwrap();
+
+        assert_eq!(Snapshot::parse(&text).unwrap(), snapshot);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn snapshot_migrates_version_1() {
+        let snapshot: Snapshot = running_sim().snapshot();
+        let mut value: Value = serde_json::to_value(&snapshot).unwrap();
+
+        value["version"] = Value::from(1);
+        let config: &mut Map<String, Value> = value["config"].as_object_mut().unwrap();
+        config.remove("species");
+        config.insert("entity_count".to_string(), Value::from(120));
+        for fish in value["fish"].as_array_mut().unwrap() {
+            let fish: &mut Map<String, Value> = fish.as_object_mut().unwrap();
+            fish.remove("species");
+            fish.remove("goal_rank");
+            fish.remove("alarm");
+        }
+
+        let migrated: Snapshot = Snapshot::parse(&value.to_string()).unwrap();
+        assert_eq!(migrated.version, SNAPSHOT_VERSION);
+        assert_eq!(migrated.config.species[0].count, 120);
+        assert_eq!(migrated.config.species[1].count, 0);
+        assert_eq!(migrated.fish.len(), snapshot.fish.len());
+        assert!(migrated.fish.iter().all(|fish| fish.species == 0));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn snapshot_rejects_other_version() {
+        let text: String = format!(r#"{{ "version": {} }}"#, SNAPSHOT_VERSION + 1);
+
+        assert!(matches!(
+            Snapshot::parse(&text),
+            Err(Error::Snapshot(msg)) if msg.starts_with("version")
+        ));
+    }
+}
@@ -0,0 +1,65 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{BoundaryMode, Config, Position, TargetVelocity, Vec2};
+use rayon::prelude::*;
+use shipyard::{IntoIter, UniqueView, View, ViewMut};
+
+const WALL_STEERING: f32 = 2.0;
+
+#[derive(Debug)]
+pub struct BoundarySteering;
+
+impl BoundarySteering {
+    pub fn system(
+        positions: View<Position>,
+        mut target_velocities: ViewMut<TargetVelocity>,
+        cfg: UniqueView<Config>,
+    ) {
+        if cfg.boundary_mode != BoundaryMode::SoftWall || cfg.boundary_margin <= 0.0 {
+            return;
+        }
+
+        let margin: f32 = cfg.boundary_margin;
+        let width: f32 = cfg.width as f32;
+        let height: f32 = cfg.height as f32;
+
+        (&positions, &mut target_velocities)
+            .par_iter()
+            .for_each(|(pos, target_vel)| {
+                let push: Vec2 = Vec2::new(
+                    Self::repulsion(pos.0.x, margin) - Self::repulsion(width - pos.0.x, margin),
+                    Self::repulsion(pos.0.y, margin) - Self::repulsion(height - pos.0.y, margin),
+                );
+
+                if push == Vec2::ZERO {
+                    return;
+                }
+
+                target_vel.0 = (target_vel.0 + push * WALL_STEERING).normalized();
+            });
+    }
+
+    #[inline]
+    fn repulsion(distance_to_wall: f32, margin: f32) -> f32 {
+        if distance_to_wall >= margin {
+            return 0.0;
+        }
+
+        let closeness: f32 = 1.0 - distance_to_wall.max(0.0) / margin;
+        closeness * closeness
+    }
+}
@@ -21,7 +21,7 @@ use shipyard::UniqueViewMut;
 pub struct CalculateDeltaTime;
 
 impl CalculateDeltaTime {
-    pub fn system(mut delta_time: UniqueViewMut<DeltaTime>) {
-        delta_time.calc();
+    pub fn system(mut delta_time: UniqueViewMut<DeltaTime>) -> usize {
+        delta_time.calc()
     }
 }
@@ -0,0 +1,130 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    Boundary, Config, FishIdentifier, Position, PredatorIdentifier, Stress, TargetSpeed,
+    TargetVelocity, Vec2,
+};
+use rayon::prelude::*;
+use shipyard::{IntoIter, UniqueView, View, ViewMut};
+
+#[derive(Debug)]
+pub struct Escape;
+
+impl Escape {
+    pub fn system(
+        positions: View<Position>,
+        fish: View<FishIdentifier>,
+        predators: View<PredatorIdentifier>,
+        mut target_velocities: ViewMut<TargetVelocity>,
+        mut target_speeds: ViewMut<TargetSpeed>,
+        mut stress: ViewMut<Stress>,
+        cfg: UniqueView<Config>,
+    ) {
+        let predator_positions: Vec<Vec2> = (&positions, &predators)
+            .iter()
+            .map(|(pos, _)| pos.0)
+            .collect();
+
+        if predator_positions.is_empty() {
+            return;
+        }
+
+        let radius: f32 = cfg.prey_detection_radius;
+        let boundary: Boundary = Boundary::from_config(&cfg);
+
+        (
+            &positions,
+            &fish,
+            &mut target_velocities,
+            &mut target_speeds,
+            &mut stress,
+        )
+            .par_iter()
+            .for_each(|(pos, _, target_vel, target_speed, stress)| {
+                let mut away: Vec2 = Vec2::ZERO;
+
+                for &predator_pos in &predator_positions {
+                    let offset: Vec2 = boundary.offset(predator_pos, pos.0);
+                    let distance: f32 = offset.length();
+                    if distance <= radius {
+                        away += offset.normalized() * (1.0 - distance / radius);
+                    }
+                }
+
+                if away == Vec2::ZERO {
+                    return;
+                }
+
+                target_vel.0 = away.normalized();
+                target_speed.0.value = cfg.behavior.escape_speed;
+                stress.0.value = cfg.behavior.escape_stress;
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Escape;
+    use crate::{
+        BehaviorParams, Config, FishIdentifier, Position, PredatorIdentifier, Scalar, Stress,
+        TargetSpeed, TargetVelocity, Vec2,
+    };
+    use shipyard::{EntityId, Get, View, World};
+
+    const IDLE_VELOCITY: Vec2 = Vec2 { x: 0.0, y: 1.0 };
+
+    #[test]
+    fn escape_flees_predators_in_range() {
+        let cfg: Config = Config::default();
+        let mut world: World = World::new();
+        world.add_unique(cfg);
+        world.add_entity((Position(Vec2::new(500.0, 500.0)), PredatorIdentifier));
+        let ids: Vec<EntityId> = [Vec2::new(540.0, 500.0), Vec2::new(700.0, 500.0)]
+            .iter()
+            .map(|&position| {
+                world.add_entity((
+                    Position(position),
+                    FishIdentifier,
+                    TargetVelocity(IDLE_VELOCITY),
+                    TargetSpeed(Scalar::new(10.0)),
+                    Stress(Scalar::ZERO),
+                ))
+            })
+            .collect();
+        assert!(cfg.prey_detection_radius > 40.0 && cfg.prey_detection_radius < 200.0);
+
+        world.run(Escape::system);
+        let fish: Vec<(Vec2, f32, f32)> = world.run(
+            |velocities: View<TargetVelocity>, speeds: View<TargetSpeed>, stress: View<Stress>| {
+                ids.iter()
+                    .map(|&id| {
+                        let (vel, speed, stress): (&TargetVelocity, &TargetSpeed, &Stress) =
+                            (&velocities, &speeds, &stress).get(id).unwrap();
+                        (vel.0, speed.0.value, stress.0.value)
+                    })
+                    .collect()
+            },
+        );
+
+        let params: BehaviorParams = BehaviorParams::default();
+        let (vel, speed, stress): (Vec2, f32, f32) = fish[0];
+        assert!(vel.distance(Vec2::new(1.0, 0.0)) < 1e-5);
+        assert_eq!(speed, params.escape_speed);
+        assert_eq!(stress, params.escape_stress);
+        assert_eq!(fish[1], (IDLE_VELOCITY, 10.0, 0.0));
+    }
+}
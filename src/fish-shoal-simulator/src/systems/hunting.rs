@@ -0,0 +1,222 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    AttackCooldown, Boundary, Captures, Chunks, Config, DeltaTime, Density, Hunger,
+    HuntingStrategy, Position, PredatorTarget, TargetSpeed, TargetVelocity, Vec2,
+};
+use shipyard::{EntityId, Get, IntoIter, UniqueView, UniqueViewMut, View, ViewMut};
+use std::cmp::Ordering;
+
+#[derive(Debug)]
+pub struct Hunting;
+
+impl Hunting {
+    pub fn system(
+        positions: View<Position>,
+        densities: View<Density>,
+        mut target_velocities: ViewMut<TargetVelocity>,
+        mut target_speeds: ViewMut<TargetSpeed>,
+        mut hungers: ViewMut<Hunger>,
+        mut targets: ViewMut<PredatorTarget>,
+        mut cooldowns: ViewMut<AttackCooldown>,
+        chunks: UniqueView<Chunks>,
+        cfg: UniqueView<Config>,
+        delta_time: UniqueView<DeltaTime>,
+        mut captures: UniqueViewMut<Captures>,
+    ) {
+        let dt: f32 = delta_time.secs();
+        let boundary: Boundary = Boundary::from_config(&cfg);
+
+        (
+            &positions,
+            &mut target_velocities,
+            &mut target_speeds,
+            &mut hungers,
+            &mut targets,
+            &mut cooldowns,
+        )
+            .iter()
+            .for_each(
+                |(pos, target_vel, target_speed, hunger, target, cooldown)| {
+                    cooldown.tick(dt);
+                    hunger.0.value = (hunger.0.value + cfg.behavior.hunger_rate * dt).min(1.0);
+
+                    let prey_position = |id: EntityId| -> Option<Vec2> {
+                        (&densities).get(id).ok()?;
+                        let prey_pos: Vec2 = (&positions).get(id).ok()?.0;
+                        (boundary.distance(pos.0, prey_pos) <= cfg.predator_vision_radius)
+                            .then_some(prey_pos)
+                    };
+
+                    if hunger.0.value < cfg.behavior.sated_hunger {
+                        target.0 = None;
+                    } else if target.0.and_then(prey_position).is_none() {
+                        target.0 = Self::choose_prey(
+                            &positions, &densities, &chunks, &cfg, boundary, pos.0,
+                        );
+                    }
+
+                    let Some((prey_id, prey_pos)) =
+                        target.0.and_then(|id| Some((id, prey_position(id)?)))
+                    else {
+                        target.0 = None;
+                        target_speed.0.value =
+                            cfg.predator_speed * cfg.behavior.wander_speed_factor;
+                        return;
+                    };
+
+                    let offset: Vec2 = boundary.offset(pos.0, prey_pos);
+                    target_vel.0 = offset.normalized();
+                    target_speed.0.value = cfg.predator_speed * (0.5 + 0.5 * hunger.0.value);
+
+                    if offset.length() <= cfg.predator_capture_radius && cooldown.is_ready() {
+                        captures.push(prey_id);
+                        hunger.0.value = 0.0;
+                        cooldown.start(cfg.predator_attack_cooldown);
+                        target.0 = None;
+                    }
+                },
+            );
+    }
+
+    fn choose_prey(
+        positions: &View<Position>,
+        densities: &View<Density>,
+        chunks: &Chunks,
+        cfg: &Config,
+        boundary: Boundary,
+        pos: Vec2,
+    ) -> Option<EntityId> {
+        chunks
+            .load_radius(&pos, cfg.predator_vision_radius)
+            .filter_map(|id| {
+                let density: usize = densities.get(id).ok()?.value;
+                let distance: f32 = boundary.distance(pos, positions.get(id).ok()?.0);
+                (distance <= cfg.predator_vision_radius).then_some((id, density, distance))
+            })
+            .min_by(|a, b| {
+                let by_density: Ordering = match cfg.predator_strategy {
+                    HuntingStrategy::Densest => b.1.cmp(&a.1),
+                    HuntingStrategy::MostIsolated => a.1.cmp(&b.1),
+                };
+                by_density.then(a.2.total_cmp(&b.2)).then(a.0.cmp(&b.0))
+            })
+            .map(|(id, _, _)| id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Hunting;
+    use crate::{
+        AttackCooldown, Captures, Chunks, Config, DeltaTime, Density, Hunger, Position,
+        PredatorTarget, Scalar, TargetSpeed, TargetVelocity, Vec2,
+    };
+    use shipyard::{EntityId, Get, UniqueViewMut, View, ViewMut, World};
+
+    const WANDER_VELOCITY: Vec2 = Vec2 { x: 1.0, y: 0.0 };
+
+    fn hunt(cfg: Config, hunger: f32, prey: &[(Vec2, usize)]) -> (World, EntityId, Vec<EntityId>) {
+        let mut world: World = World::new();
+        let mut chunks: Chunks = Chunks::new(&cfg);
+        let prey: Vec<EntityId> = prey
+            .iter()
+            .map(|&(position, density)| {
+                let mut value: Density = Density::default();
+                value.set(density);
+                let id: EntityId = world.add_entity((Position(position), value));
+                chunks.update(id, &position);
+                id
+            })
+            .collect();
+        let predator: EntityId = world.add_entity((
+            Position(Vec2::new(500.0, 500.0)),
+            TargetVelocity(WANDER_VELOCITY),
+            TargetSpeed(Scalar::ZERO),
+            Hunger(Scalar::new(hunger)),
+            PredatorTarget::default(),
+            AttackCooldown::default(),
+        ));
+        world.add_unique(chunks);
+        world.add_unique(cfg);
+        world.add_unique(DeltaTime::default());
+        world.add_unique(Captures::default());
+
+        world.run(Hunting::system);
+        (world, predator, prey)
+    }
+
+    fn predator(world: &World, id: EntityId) -> (Vec2, f32, f32, Option<EntityId>) {
+        world.run(
+            |velocities: View<TargetVelocity>,
+             speeds: View<TargetSpeed>,
+             hungers: View<Hunger>,
+             targets: View<PredatorTarget>| {
+                let (vel, speed, hunger, target): (
+                    &TargetVelocity,
+                    &TargetSpeed,
+                    &Hunger,
+                    &PredatorTarget,
+                ) = (&velocities, &speeds, &hungers, &targets).get(id).unwrap();
+                (vel.0, speed.0.value, hunger.0.value, target.0)
+            },
+        )
+    }
+
+    #[test]
+    fn hunting_hungry_predator_closes_on_prey() {
+        let cfg: Config = Config::default();
+        let (world, id, prey): (World, EntityId, Vec<EntityId>) = hunt(
+            cfg,
+            1.0,
+            &[(Vec2::new(560.0, 500.0), 3), (Vec2::new(500.0, 440.0), 1)],
+        );
+
+        let (vel, speed, _, target): (Vec2, f32, f32, Option<EntityId>) = predator(&world, id);
+        assert_eq!(target, Some(prey[1]));
+        assert!(vel.distance(Vec2::new(0.0, -1.0)) < 1e-5);
+        assert_eq!(speed, cfg.predator_speed);
+
+        world.run(|mut positions: ViewMut<Position>| {
+            (&mut positions).get(prey[1]).unwrap().0 = Vec2::new(500.0, 497.0);
+        });
+        world.run(Hunting::system);
+
+        let (_, _, hunger, target): (Vec2, f32, f32, Option<EntityId>) = predator(&world, id);
+        assert_eq!(hunger, 0.0);
+        assert_eq!(target, None);
+        assert_eq!(
+            world.run(|mut captures: UniqueViewMut<Captures>| captures.take()),
+            vec![prey[1]]
+        );
+    }
+
+    #[test]
+    fn hunting_sated_predator_wanders() {
+        let cfg: Config = Config::default();
+        let (world, id, _): (World, EntityId, Vec<EntityId>) = hunt(
+            cfg,
+            cfg.behavior.sated_hunger / 2.0,
+            &[(Vec2::new(560.0, 500.0), 1)],
+        );
+
+        let (vel, speed, _, target): (Vec2, f32, f32, Option<EntityId>) = predator(&world, id);
+        assert_eq!(target, None);
+        assert_eq!(vel, WANDER_VELOCITY);
+        assert_eq!(speed, cfg.predator_speed * cfg.behavior.wander_speed_factor);
+    }
+}
@@ -14,12 +14,13 @@
  * limitations under the License.
  */
 
-use crate::{DeltaTime, Speed, Stress, TargetSpeed, TargetVelocity, Velocity};
+use crate::{
+    Config, DeltaTime, Species, Speed, SteeringMode, Stress, TargetSpeed, TargetVelocity, Traits,
+    Velocity,
+};
 use rayon::prelude::*;
 use shipyard::{IntoIter, UniqueView, View, ViewMut};
 
-const EPSILON: f32 = 0.1;
-
 #[derive(Debug)]
 pub struct LerpToTarget;
 
@@ -30,9 +31,18 @@ impl LerpToTarget {
         mut speeds: ViewMut<Speed>,
         target_speeds: View<TargetSpeed>,
         stress: View<Stress>,
+        traits: View<Traits>,
+        species: View<Species>,
         delta_time: UniqueView<DeltaTime>,
+        cfg: UniqueView<Config>,
     ) {
+        if cfg.steering_mode != SteeringMode::Lerp {
+            return;
+        }
+
         let dt: DeltaTime = *delta_time;
+        let rate: f32 = cfg.behavior.lerp_rate;
+        let epsilon: f32 = cfg.behavior.lerp_epsilon;
 
         (
             &mut velocities,
@@ -40,22 +50,29 @@ impl LerpToTarget {
             &mut speeds,
             &target_speeds,
             &stress,
+            traits.as_optional(),
+            species.as_optional(),
         )
             .par_iter()
-            .for_each(|(vel, target_vel, speed, target_speed, stress)| {
-                let factor: f32 = stress.0.value * dt * 5.0;
+            .for_each(
+                |(vel, target_vel, speed, target_speed, stress, traits, species)| {
+                    let agility: f32 = traits.zip(species).map_or(1.0, |(traits, species)| {
+                        traits.agility(&cfg.species[species.0].traits)
+                    });
+                    let factor: f32 = stress.0.value * dt * rate * agility;
 
-                if (vel.0 - target_vel.0).length() <= EPSILON {
-                    vel.0 = target_vel.0;
-                } else {
-                    vel.0 = vel.0.lerp(target_vel.0, factor);
-                }
+                    if (vel.0 - target_vel.0).length() <= epsilon {
+                        vel.0 = target_vel.0;
+                    } else {
+                        vel.0 = vel.0.lerp(target_vel.0, factor);
+                    }
 
-                if (speed.0 - target_speed.0).abs().value <= EPSILON {
-                    speed.0 = target_speed.0;
-                } else {
-                    speed.0 = speed.0.lerp(target_speed.0, factor);
-                }
-            });
+                    if (speed.0 - target_speed.0).abs().value <= epsilon {
+                        speed.0 = target_speed.0;
+                    } else {
+                        speed.0 = speed.0.lerp(target_speed.0, factor);
+                    }
+                },
+            );
     }
 }
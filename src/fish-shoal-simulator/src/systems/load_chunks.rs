@@ -14,7 +14,7 @@
  * limitations under the License.
  */
 
-use crate::{Chunks, Config, Position};
+use crate::{Chunks, Config, FishIdentifier, Position};
 use shipyard::{EntityId, IntoIter, UniqueView, UniqueViewMut, View};
 
 #[derive(Debug)]
@@ -23,17 +23,19 @@ pub struct LoadChunks;
 impl LoadChunks {
     pub fn system(
         positions: View<Position>,
+        fish: View<FishIdentifier>,
         cfg: UniqueView<Config>,
         mut chunks: UniqueViewMut<Chunks>,
     ) {
-        chunks.clear();
-        chunks.resize(cfg.attraction_radius);
+        chunks.configure(&cfg);
+        chunks.begin_update();
 
-        (&positions)
-            .iter()
-            .with_id()
-            .for_each(|(id, pos): (EntityId, &Position)| {
-                chunks.store(&pos.0, id);
-            });
+        (&positions, &fish).iter().with_id().for_each(
+            |(id, (pos, _)): (EntityId, (&Position, &FishIdentifier))| {
+                chunks.update(id, &pos.0);
+            },
+        );
+
+        chunks.sweep();
     }
 }
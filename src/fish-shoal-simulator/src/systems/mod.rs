@@ -14,18 +14,38 @@
  * limitations under the License.
  */
 
+mod advance_rng;
+mod boundary_steering;
 mod calculate_delta_time;
+mod escape;
+mod hunting;
 mod lerp_to_target;
 mod load_chunks;
 mod motion;
+mod mouse_interaction;
+mod navigation;
+mod obstacle_avoidance;
+mod obstacle_collision;
 mod out_of_bound;
 mod random_behavior;
+mod steer_to_target;
+mod stress_contagion;
 mod swarming;
 
+pub use advance_rng::AdvanceRng;
+pub use boundary_steering::BoundarySteering;
 pub use calculate_delta_time::CalculateDeltaTime;
+pub use escape::Escape;
+pub use hunting::Hunting;
 pub use lerp_to_target::LerpToTarget;
 pub use load_chunks::LoadChunks;
 pub use motion::Motion;
+pub use mouse_interaction::MouseInteraction;
+pub use navigation::Navigation;
+pub use obstacle_avoidance::ObstacleAvoidance;
+pub use obstacle_collision::ObstacleCollision;
 pub use out_of_bound::OutOfBound;
 pub use random_behavior::RandomBehavior;
+pub use steer_to_target::SteerToTarget;
+pub use stress_contagion::StressContagion;
 pub use swarming::Swarming;
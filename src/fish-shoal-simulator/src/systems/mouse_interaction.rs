@@ -0,0 +1,224 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    BehaviorParams, Config, FishIdentifier, MouseMode, Position, Stress, TargetSpeed,
+    TargetVelocity, Vec2,
+};
+use rayon::prelude::*;
+use shipyard::{IntoIter, UniqueView, View, ViewMut};
+
+#[derive(Debug)]
+pub struct MouseInteraction;
+
+impl MouseInteraction {
+    pub fn system(
+        positions: View<Position>,
+        fish: View<FishIdentifier>,
+        mut target_velocities: ViewMut<TargetVelocity>,
+        mut target_speeds: ViewMut<TargetSpeed>,
+        mut stress: ViewMut<Stress>,
+        cfg: UniqueView<Config>,
+    ) {
+        let Some(mouse_pos) = cfg.mouse_pos.map(Vec2::from) else {
+            return;
+        };
+
+        let params: &BehaviorParams = &cfg.behavior;
+        let radius: f32 = cfg.mouse_radius;
+        let urgency: f32 =
+            (cfg.mouse_speed.unwrap_or(0.0) / params.mouse_panic_speed).clamp(0.0, 1.0);
+
+        match cfg.mouse_mode {
+            MouseMode::None => (),
+            MouseMode::Predator => (
+                &positions,
+                &fish,
+                &mut target_velocities,
+                &mut target_speeds,
+                &mut stress,
+            )
+                .par_iter()
+                .for_each(|(pos, _, target_vel, target_speed, stress)| {
+                    let offset: Vec2 = pos.0 - mouse_pos;
+                    let distance: f32 = offset.length();
+                    if distance > radius {
+                        return;
+                    }
+
+                    let proximity: f32 = 1.0 - distance / radius;
+                    let panic: f32 = (proximity * (1.0 + urgency)).min(1.0);
+
+                    target_vel.0 = offset.normalized();
+                    target_speed.0.value = target_speed
+                        .0
+                        .value
+                        .max(params.mouse_flee_speed * (1.0 + urgency));
+                    stress.0.value = stress.0.value.max(panic);
+                }),
+            MouseMode::Attractor => (
+                &positions,
+                &fish,
+                &mut target_velocities,
+                &mut target_speeds,
+                &mut stress,
+            )
+                .par_iter()
+                .for_each(|(pos, _, target_vel, target_speed, stress)| {
+                    let offset: Vec2 = mouse_pos - pos.0;
+                    let distance: f32 = offset.length();
+                    if distance > radius || distance <= params.mouse_feed_distance {
+                        return;
+                    }
+
+                    target_vel.0 = offset.normalized();
+                    target_speed.0.value = params.mouse_feed_speed;
+                    stress.0.value = stress.0.value.max(params.mouse_feed_stress);
+                }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MouseInteraction;
+    use crate::{
+        BehaviorParams, Config, FishIdentifier, MouseMode, Position, Scalar, Stress, TargetSpeed,
+        TargetVelocity, Vec2,
+    };
+    use shipyard::{EntityId, Get, View, World};
+
+    const IDLE_VELOCITY: Vec2 = Vec2 { x: 0.0, y: 1.0 };
+    const IDLE_SPEED: f32 = 10.0;
+
+    fn mouse(mouse_mode: MouseMode, mouse_speed: Option<f32>) -> Config {
+        Config {
+            mouse_pos: Some([100.0, 100.0]),
+            mouse_speed,
+            mouse_mode,
+            mouse_radius: 100.0,
+            ..Config::default()
+        }
+    }
+
+    fn interact(cfg: Config, positions: &[Vec2]) -> Vec<(Vec2, f32, f32)> {
+        let mut world: World = World::new();
+        world.add_unique(cfg);
+        let ids: Vec<EntityId> = positions
+            .iter()
+            .map(|&position| {
+                world.add_entity((
+                    Position(position),
+                    FishIdentifier,
+                    TargetVelocity(IDLE_VELOCITY),
+                    TargetSpeed(Scalar::new(IDLE_SPEED)),
+                    Stress(Scalar::ZERO),
+                ))
+            })
+            .collect();
+
+        world.run(MouseInteraction::system);
+        world.run(
+            |velocities: View<TargetVelocity>, speeds: View<TargetSpeed>, stress: View<Stress>| {
+                ids.iter()
+                    .map(|&id| {
+                        let (vel, speed, stress): (&TargetVelocity, &TargetSpeed, &Stress) =
+                            (&velocities, &speeds, &stress).get(id).unwrap();
+                        (vel.0, speed.0.value, stress.0.value)
+                    })
+                    .collect()
+            },
+        )
+    }
+
+    fn is_idle(&(vel, speed, stress): &(Vec2, f32, f32)) -> bool {
+        vel == IDLE_VELOCITY && speed == IDLE_SPEED && stress == 0.0
+    }
+
+    #[test]
+    fn mouse_predator_scares_fish_in_radius() {
+        let fish: Vec<(Vec2, f32, f32)> = interact(
+            mouse(MouseMode::Predator, None),
+            &[Vec2::new(150.0, 100.0), Vec2::new(250.0, 100.0)],
+        );
+
+        let (vel, speed, stress): (Vec2, f32, f32) = fish[0];
+        assert!(vel.distance(Vec2::new(1.0, 0.0)) < 1e-5);
+        assert_eq!(speed, BehaviorParams::default().mouse_flee_speed);
+        assert!((stress - 0.5).abs() < 1e-5);
+        assert!(is_idle(&fish[1]));
+    }
+
+    #[test]
+    fn mouse_attractor_draws_fish_in_radius() {
+        let fish: Vec<(Vec2, f32, f32)> = interact(
+            mouse(MouseMode::Attractor, None),
+            &[
+                Vec2::new(100.0, 150.0),
+                Vec2::new(100.0, 250.0),
+                Vec2::new(102.0, 100.0),
+            ],
+        );
+
+        let (vel, speed, stress): (Vec2, f32, f32) = fish[0];
+        assert!(vel.distance(Vec2::new(0.0, -1.0)) < 1e-5);
+        assert_eq!(speed, BehaviorParams::default().mouse_feed_speed);
+        assert_eq!(stress, BehaviorParams::default().mouse_feed_stress);
+        assert!(is_idle(&fish[1]));
+        assert!(is_idle(&fish[2]));
+    }
+
+    #[test]
+    fn mouse_ignored_without_mode_or_pointer() {
+        let positions: [Vec2; 1] = [Vec2::new(150.0, 100.0)];
+
+        assert!(interact(mouse(MouseMode::None, None), &positions)
+            .iter()
+            .all(is_idle));
+        assert!(interact(
+            Config {
+                mouse_pos: None,
+                ..mouse(MouseMode::Predator, None)
+            },
+            &positions
+        )
+        .iter()
+        .all(is_idle));
+    }
+
+    #[test]
+    fn mouse_urgency_scales_with_speed() {
+        let reactions: Vec<(Vec2, f32, f32)> = [None, Some(250.0), Some(500.0), Some(5_000.0)]
+            .into_iter()
+            .map(|mouse_speed| {
+                interact(
+                    mouse(MouseMode::Predator, mouse_speed),
+                    &[Vec2::new(160.0, 100.0)],
+                )[0]
+            })
+            .collect();
+
+        for pair in reactions.windows(2) {
+            assert!(pair[0].1 < pair[1].1);
+            assert!(pair[0].2 < pair[1].2);
+        }
+        assert_eq!(
+            reactions[3].1,
+            2.0 * BehaviorParams::default().mouse_flee_speed
+        );
+        assert!((reactions[3].2 - 0.8).abs() < 1e-5);
+    }
+}
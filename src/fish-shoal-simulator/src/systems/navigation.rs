@@ -0,0 +1,51 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{Boundary, Config, GoalRank, Position, TargetVelocity, Vec2};
+use rayon::prelude::*;
+use shipyard::{IntoIter, UniqueView, View, ViewMut};
+
+#[derive(Debug)]
+pub struct Navigation;
+
+impl Navigation {
+    pub fn system(
+        positions: View<Position>,
+        ranks: View<GoalRank>,
+        mut target_velocities: ViewMut<TargetVelocity>,
+        cfg: UniqueView<Config>,
+    ) {
+        if cfg.informed_fraction <= 0.0 {
+            return;
+        }
+
+        let boundary: Boundary = Boundary::from_config(&cfg);
+
+        (&positions, &ranks, &mut target_velocities)
+            .par_iter()
+            .for_each(|(pos, rank, target_vel)| {
+                let Some(goal) = cfg.goal(rank.0) else {
+                    return;
+                };
+
+                let heading: Vec2 = cfg.goals[goal].heading(pos.0, boundary);
+                let blended: Vec2 = (target_vel.0 + heading * cfg.goal_weight).normalized();
+                if blended != Vec2::ZERO {
+                    target_vel.0 = blended;
+                }
+            });
+    }
+}
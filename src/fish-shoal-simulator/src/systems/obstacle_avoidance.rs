@@ -0,0 +1,62 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{Angle, Config, Obstacles, Position, TargetVelocity, Vec2, Velocity};
+use rayon::prelude::*;
+use shipyard::{IntoIter, UniqueView, View, ViewMut};
+
+const RAY_STEP_DEGREES: f32 = 20.0;
+const RAY_PAIRS: usize = 8;
+
+#[derive(Debug)]
+pub struct ObstacleAvoidance;
+
+impl ObstacleAvoidance {
+    pub fn system(
+        positions: View<Position>,
+        velocities: View<Velocity>,
+        mut target_velocities: ViewMut<TargetVelocity>,
+        obstacles: UniqueView<Obstacles>,
+        cfg: UniqueView<Config>,
+    ) {
+        if obstacles.is_empty() {
+            return;
+        }
+
+        let look_ahead: f32 = cfg.obstacle_look_ahead;
+
+        (&positions, &velocities, &mut target_velocities)
+            .par_iter()
+            .for_each(|(pos, vel, target_vel)| {
+                let dir: Vec2 = vel.0.normalized();
+                if obstacles.ray_hit(pos.0, dir, look_ahead).is_none() {
+                    return;
+                }
+
+                let heading: Angle = Angle::from_vector(dir);
+
+                let clear_dir: Option<Vec2> = (1..=RAY_PAIRS)
+                    .flat_map(|i| {
+                        let offset: Angle = Angle::from_degrees(RAY_STEP_DEGREES * i as f32);
+                        [heading + offset, heading - offset]
+                    })
+                    .map(Angle::to_vector)
+                    .find(|&candidate| obstacles.ray_hit(pos.0, candidate, look_ahead).is_none());
+
+                target_vel.0 = clear_dir.unwrap_or(-dir);
+            });
+    }
+}
@@ -0,0 +1,39 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{Obstacles, Position, SafePosition};
+use rayon::prelude::*;
+use shipyard::{IntoIter, UniqueView, ViewMut};
+
+const COLLISION_MARGIN: f32 = 0.5;
+
+#[derive(Debug)]
+pub struct ObstacleCollision;
+
+impl ObstacleCollision {
+    pub fn system(
+        mut positions: ViewMut<Position>,
+        mut safe_positions: ViewMut<SafePosition>,
+        obstacles: UniqueView<Obstacles>,
+    ) {
+        (&mut positions, &mut safe_positions)
+            .par_iter()
+            .for_each(|(pos, safe_pos)| {
+                pos.0 = obstacles.push_out(pos.0, safe_pos.0, COLLISION_MARGIN);
+                safe_pos.0 = pos.0;
+            });
+    }
+}
@@ -14,7 +14,7 @@
  * limitations under the License.
  */
 
-use crate::{Config, Position};
+use crate::{Boundary, BoundaryMode, Config, Position, TargetVelocity, Velocity};
 use rayon::prelude::*;
 use shipyard::{IntoIter, UniqueView, ViewMut};
 
@@ -22,22 +22,47 @@ use shipyard::{IntoIter, UniqueView, ViewMut};
 pub struct OutOfBound;
 
 impl OutOfBound {
-    pub fn system(mut positions: ViewMut<Position>, cfg: UniqueView<Config>) {
-        let width: f32 = cfg.width as f32;
-        let height: f32 = cfg.height as f32;
+    pub fn system(
+        mut positions: ViewMut<Position>,
+        mut velocities: ViewMut<Velocity>,
+        mut target_velocities: ViewMut<TargetVelocity>,
+        cfg: UniqueView<Config>,
+    ) {
+        let boundary: Boundary = Boundary::from_config(&cfg);
 
-        (&mut positions).par_iter().for_each(|pos| {
-            if pos.0.x <= 0.0 {
-                pos.0.x = width - 1.0;
-            } else if pos.0.x >= width {
-                pos.0.x = 1.0;
-            }
+        match boundary.mode {
+            BoundaryMode::Wrap => (&mut positions).par_iter().for_each(|pos| {
+                pos.0 = boundary.wrap(pos.0);
+            }),
+            BoundaryMode::Reflect => (&mut positions, &mut velocities, &mut target_velocities)
+                .par_iter()
+                .for_each(|(pos, vel, target_vel)| {
+                    if pos.0.x < 0.0 || pos.0.x >= boundary.width {
+                        pos.0.x = Self::mirror(pos.0.x, boundary.width);
+                        vel.0.x = -vel.0.x;
+                        target_vel.0.x = -target_vel.0.x;
+                    }
 
-            if pos.0.y <= 0.0 {
-                pos.0.y = height - 1.0;
-            } else if pos.0.y >= height {
-                pos.0.y = 1.0;
-            }
-        });
+                    if pos.0.y < 0.0 || pos.0.y >= boundary.height {
+                        pos.0.y = Self::mirror(pos.0.y, boundary.height);
+                        vel.0.y = -vel.0.y;
+                        target_vel.0.y = -target_vel.0.y;
+                    }
+
+                    pos.0 = boundary.clamp(pos.0);
+                }),
+            BoundaryMode::SoftWall => (&mut positions).par_iter().for_each(|pos| {
+                pos.0 = boundary.clamp(pos.0);
+            }),
+        }
+    }
+
+    #[inline]
+    fn mirror(value: f32, size: f32) -> f32 {
+        if value < 0.0 {
+            -value
+        } else {
+            2.0 * size - value
+        }
     }
 }
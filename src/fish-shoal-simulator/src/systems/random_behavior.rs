@@ -14,8 +14,11 @@
  * limitations under the License.
  */
 
-use crate::{Config, Social, Scalar, Speed, Stress, TargetSpeed, TargetVelocity, Vec2, Velocity};
-use rand::{rngs::ThreadRng, Rng};
+use crate::{
+    Config, RngKey, Scalar, SimRng, Social, Speed, StreamRng, Stress, StressMode, TargetSpeed,
+    TargetVelocity, Traits, Vec2, Velocity,
+};
+use rand::Rng;
 use rayon::prelude::*;
 use shipyard::{IntoIter, UniqueView, View, ViewMut};
 
@@ -30,7 +33,10 @@ impl RandomBehavior {
         mut target_speeds: ViewMut<TargetSpeed>,
         mut stress: ViewMut<Stress>,
         socials: View<Social>,
+        keys: View<RngKey>,
+        traits: View<Traits>,
         cfg: UniqueView<Config>,
+        sim_rng: UniqueView<SimRng>,
     ) {
         (
             &velocities,
@@ -39,32 +45,51 @@ impl RandomBehavior {
             &mut target_speeds,
             &mut stress,
             &socials,
+            &keys,
+            &traits,
         )
             .par_iter()
-            .for_each(|(vel, target_vel, speed, target_speed, stress, social)| {
-                if social.is_in_group {
-                    return;
-                }
+            .for_each(
+                |(vel, target_vel, speed, target_speed, stress, social, key, traits)| {
+                    if social.is_in_group {
+                        return;
+                    }
 
-                let mut rng: ThreadRng = rand::rng();
+                    let boldness: f64 = 2.0 * traits.boldness as f64;
 
-                if vel.0 == target_vel.0 && rng.random_bool(cfg.direction_change_prob) {
-                    let random_direction: Vec2 = Vec2::random_dir(&mut rng);
-                    target_vel.0 = target_vel
-                        .0
-                        .lerp(random_direction, rng.random_range(0.0..1.0));
-                }
+                    let mut rng: StreamRng = sim_rng.stream(key.0, SimRng::BEHAVIOR);
 
-                if speed.0 == target_speed.0 && rng.random_bool(cfg.speed_change_prob) {
-                    let random_speed: Scalar = Scalar::new_random(&mut rng, 10.0..100.0);
-                    target_speed.0 = target_speed
-                        .0
-                        .lerp(random_speed, rng.random_range(0.0..1.0));
-                }
+                    if vel.0 == target_vel.0
+                        && rng.random_bool((cfg.direction_change_prob * boldness).min(1.0))
+                    {
+                        let random_direction: Vec2 = Vec2::random_dir(&mut rng);
+                        target_vel.0 = target_vel
+                            .0
+                            .lerp(random_direction, rng.random_range(0.0..1.0));
+                    }
 
-                if rng.random_bool(cfg.stress_change_prob) {
-                    stress.0 = Scalar::new_random(&mut rng, 0.1..0.5);
-                }
-            });
+                    if speed.0 == target_speed.0
+                        && rng.random_bool((cfg.speed_change_prob * boldness).min(1.0))
+                    {
+                        let random_speed: Scalar = Scalar::new_random(
+                            &mut rng,
+                            cfg.behavior.idle_speed_min * traits.speed
+                                ..cfg.behavior.idle_speed_max * traits.speed,
+                        );
+                        target_speed.0 = target_speed
+                            .0
+                            .lerp(random_speed, rng.random_range(0.0..1.0));
+                    }
+
+                    if rng.random_bool(cfg.stress_change_prob)
+                        && cfg.stress_mode == StressMode::Rules
+                    {
+                        stress.0 = Scalar::new_random(
+                            &mut rng,
+                            cfg.behavior.idle_stress_min..cfg.behavior.idle_stress_max,
+                        );
+                    }
+                },
+            );
     }
 }
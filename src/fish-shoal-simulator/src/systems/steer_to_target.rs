@@ -0,0 +1,162 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    Angle, Config, DeltaTime, Scalar, Species, Speed, SteeringMode, TargetSpeed, TargetVelocity,
+    Traits, Vec2, Velocity,
+};
+use rayon::prelude::*;
+use shipyard::{IntoIter, UniqueView, View, ViewMut};
+
+#[derive(Debug)]
+pub struct SteerToTarget;
+
+impl SteerToTarget {
+    pub fn system(
+        mut velocities: ViewMut<Velocity>,
+        target_velocities: View<TargetVelocity>,
+        mut speeds: ViewMut<Speed>,
+        target_speeds: View<TargetSpeed>,
+        traits: View<Traits>,
+        species: View<Species>,
+        delta_time: UniqueView<DeltaTime>,
+        cfg: UniqueView<Config>,
+    ) {
+        if cfg.steering_mode != SteeringMode::Force {
+            return;
+        }
+
+        let dt: f32 = delta_time.secs();
+        let max_turn: Angle = Angle::from_degrees(cfg.max_turn_rate) * dt;
+
+        (
+            &mut velocities,
+            &target_velocities,
+            &mut speeds,
+            &target_speeds,
+            traits.as_optional(),
+            species.as_optional(),
+        )
+            .par_iter()
+            .for_each(|(vel, target_vel, speed, target_speed, traits, species)| {
+                let (agility, speed_factor): (f32, f32) =
+                    traits.zip(species).map_or((1.0, 1.0), |(traits, species)| {
+                        (traits.agility(&cfg.species[species.0].traits), traits.speed)
+                    });
+
+                vel.0 = Self::turn(vel.0, target_vel.0, max_turn * agility);
+                speed.0 = Self::accelerate(speed.0, target_speed.0, dt, speed_factor, &cfg);
+            });
+    }
+
+    fn turn(heading: Vec2, target: Vec2, max_turn: Angle) -> Vec2 {
+        if target == Vec2::ZERO {
+            return heading.normalized();
+        }
+        if heading == Vec2::ZERO {
+            return target.normalized();
+        }
+
+        let diff: Angle = (Angle::from_vector(target) - Angle::from_vector(heading)).normalized();
+        let turn: Angle = Angle::new(diff.radians.clamp(-max_turn.radians, max_turn.radians));
+
+        (Angle::from_vector(heading) + turn).to_vector()
+    }
+
+    fn accelerate(
+        speed: Scalar,
+        target: Scalar,
+        dt: f32,
+        speed_factor: f32,
+        cfg: &Config,
+    ) -> Scalar {
+        if dt <= 0.0 {
+            return speed;
+        }
+
+        let drag: f32 = cfg.drag * (speed.value - cfg.cruise_speed * speed_factor);
+        let thrust: f32 = ((target.value - speed.value) / dt + drag)
+            .clamp(-cfg.max_deceleration, cfg.max_acceleration);
+
+        Scalar::new((speed.value + (thrust - drag) * dt).max(0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SteerToTarget;
+    use crate::{Angle, Config, Scalar, Vec2};
+
+    #[test]
+    fn steer_turn_is_bounded() {
+        let heading: Vec2 = SteerToTarget::turn(
+            Vec2::new(1.0, 0.0),
+            Vec2::new(-1.0, 0.01),
+            Angle::from_degrees(90.0),
+        );
+
+        assert!(heading.x.abs() < 1e-5);
+        assert!((heading.y - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn steer_turn_reaches_close_target() {
+        let target: Vec2 = Angle::from_degrees(10.0).to_vector();
+        let heading: Vec2 =
+            SteerToTarget::turn(Vec2::new(1.0, 0.0), target, Angle::from_degrees(90.0));
+
+        assert!(heading.distance(target) < 1e-5);
+    }
+
+    #[test]
+    fn steer_acceleration_is_bounded() {
+        let cfg: Config = Config {
+            max_acceleration: 100.0,
+            max_deceleration: 50.0,
+            drag: 0.0,
+            ..Config::default()
+        };
+
+        let faster: Scalar =
+            SteerToTarget::accelerate(Scalar::ZERO, Scalar::new(100.0), 0.1, 1.0, &cfg);
+        assert!((faster.value - 10.0).abs() < 1e-4);
+
+        let slower: Scalar =
+            SteerToTarget::accelerate(Scalar::new(100.0), Scalar::ZERO, 0.1, 1.0, &cfg);
+        assert!((slower.value - 95.0).abs() < 1e-4);
+
+        let reached: Scalar =
+            SteerToTarget::accelerate(Scalar::new(50.0), Scalar::new(55.0), 0.1, 1.0, &cfg);
+        assert!((reached.value - 55.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn steer_drag_limits_top_speed() {
+        let cfg: Config = Config {
+            max_acceleration: 100.0,
+            drag: 1.0,
+            cruise_speed: 50.0,
+            ..Config::default()
+        };
+
+        let mut speed: Scalar = Scalar::new(50.0);
+        for _ in 0..10_000 {
+            speed = SteerToTarget::accelerate(speed, Scalar::new(1_000.0), 0.01, 1.0, &cfg);
+        }
+
+        assert!((speed.value - 150.0).abs() < 1e-2);
+    }
+}
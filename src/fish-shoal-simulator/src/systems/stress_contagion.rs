@@ -0,0 +1,140 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use crate::{
+    Alarm, AlarmWave, Boundary, Chunks, Config, DeltaTime, Position, RngKey, SimRng, StreamRng,
+    Stress, StressMode, StressParams, TargetSpeed, TargetVelocity, Vec2,
+};
+use rand::Rng;
+use shipyard::{EntityId, Get, IntoIter, UniqueView, UniqueViewMut, View, ViewMut};
+
+#[derive(Debug)]
+pub struct StressContagion;
+
+impl StressContagion {
+    pub fn system(
+        positions: View<Position>,
+        keys: View<RngKey>,
+        mut stress: ViewMut<Stress>,
+        mut alarms: ViewMut<Alarm>,
+        mut target_velocities: ViewMut<TargetVelocity>,
+        mut target_speeds: ViewMut<TargetSpeed>,
+        chunks: UniqueView<Chunks>,
+        sim_rng: UniqueView<SimRng>,
+        delta_time: UniqueView<DeltaTime>,
+        mut wave: UniqueViewMut<AlarmWave>,
+        cfg: UniqueView<Config>,
+    ) {
+        if cfg.stress_mode != StressMode::Contagion {
+            return;
+        }
+
+        let params: StressParams = cfg.stress;
+        let dt: f32 = delta_time.secs();
+        let decay: f32 = (-params.decay_rate * dt).exp();
+        let boundary: Boundary = Boundary::from_config(&cfg);
+
+        wave.advance(dt);
+
+        let mut is_active: bool = false;
+        let mut crossings: Vec<(EntityId, u64, Vec2, bool)> = Vec::new();
+
+        for (id, (pos, key, stress, alarm, target_vel, target_speed)) in (
+            &positions,
+            &keys,
+            &mut stress,
+            &mut alarms,
+            &mut target_velocities,
+            &mut target_speeds,
+        )
+            .iter()
+            .with_id()
+        {
+            alarm.refractory = (alarm.refractory - dt).max(0.0);
+            stress.0.value = params.baseline + (stress.0.value - params.baseline) * decay;
+
+            let mut is_transmitted: bool = false;
+            if let Some(delay) = alarm.delay {
+                if delay <= dt {
+                    alarm.delay = None;
+                    stress.0.value = stress.0.value.max(params.alarm_stress);
+                    is_transmitted = true;
+                } else {
+                    alarm.delay = Some(delay - dt);
+                }
+            }
+
+            let was_alarmed: bool = alarm.is_alarmed;
+            alarm.is_alarmed = stress.0.value >= params.alarm_threshold;
+
+            if alarm.is_alarmed {
+                target_speed.0.value = target_speed
+                    .0
+                    .value
+                    .max(params.alarm_speed * stress.0.value);
+
+                if alarm.away != Vec2::ZERO {
+                    target_vel.0 = alarm.away;
+                }
+
+                if !was_alarmed {
+                    alarm.refractory = params.refractory_period;
+                    crossings.push((id, key.0, pos.0, is_transmitted));
+                }
+            } else if alarm.delay.is_none() {
+                alarm.away = Vec2::ZERO;
+            }
+
+            is_active |= alarm.is_active();
+        }
+
+        for (id, key, pos, is_transmitted) in crossings {
+            if is_transmitted {
+                let distance: f32 = boundary.distance(wave.origin(), pos);
+                wave.reach(distance);
+            } else if !wave.is_active() {
+                wave.start(pos);
+            }
+
+            let mut neighbors: Vec<EntityId> = chunks
+                .load_radius(&pos, params.transmission_radius)
+                .filter(|&other| other != id)
+                .collect();
+            neighbors.sort();
+
+            let mut rng: StreamRng = sim_rng.stream(key, SimRng::ALARM);
+            for other in neighbors {
+                let Ok((other_pos, mut other_alarm)) = (&positions, &mut alarms).get(other) else {
+                    continue;
+                };
+
+                if !other_alarm.can_receive()
+                    || boundary.distance(pos, other_pos.0) > params.transmission_radius
+                    || !rng.random_bool(params.transmission_probability)
+                {
+                    continue;
+                }
+
+                other_alarm.delay = Some(params.transmission_delay);
+                other_alarm.away = boundary.offset(pos, other_pos.0).normalized();
+                is_active = true;
+            }
+        }
+
+        if wave.is_active() && !is_active {
+            wave.finish();
+        }
+    }
+}
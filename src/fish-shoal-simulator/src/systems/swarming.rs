@@ -15,22 +15,36 @@
  */
 
 use crate::{
-    algo::SchoolingMechanism, /* Scalar, */ Chunks, Config, Density, Position, Social, Stress,
-    TargetSpeed, TargetVelocity, Vec2,
+    algo::{Behavior, BehaviorModel, Interaction, InteractionScratch, Neighbor, Neighborhood},
+    BehaviorParams, Boundary, Chunks, Config, Density, InteractionMode, Position, RngKey, Scalar,
+    SimRng, Social, Species, SpeciesInteraction, StreamRng, Stress, StressMode, TargetSpeed,
+    TargetVelocity, Traits, Vec2, Velocity,
 };
-use shipyard::{EntityId, IntoIter, UniqueView, View, ViewMut};
-use std::collections::{HashMap, HashSet};
+use rayon::prelude::*;
+use shipyard::{EntityId, Get, IntoIter, UniqueView, View, ViewMut};
 
-macro_rules! collect_components {
-    ($c:ident) => {
-        $c.iter().with_id().map(|(i, v)| (i, v.0)).collect()
-    };
+const NO_SLOT: u32 = u32::MAX;
+
+#[derive(Debug, Copy, Clone)]
+struct Sample {
+    id: EntityId,
+    key: u64,
+    position: Vec2,
+    heading: Vec2,
+    velocity: Vec2,
+    speed: Scalar,
+    stress: Scalar,
+    traits: Traits,
+    species: usize,
 }
 
-macro_rules! neighbors {
-    ($o:ident, $c:ident) => {
-        $o.iter().filter_map(|&i| Some((i, *$c.get(&i)?))).collect()
-    };
+#[derive(Debug, Copy, Clone)]
+struct Decision {
+    velocity: Vec2,
+    speed: Scalar,
+    stress: Scalar,
+    density: usize,
+    is_in_group: bool,
 }
 
 #[derive(Debug)]
@@ -38,77 +52,507 @@ pub struct Swarming;
 
 impl Swarming {
     pub fn system(
-        positions: View<Position>,
+        (positions, headings): (View<Position>, View<Velocity>),
+        keys: View<RngKey>,
+        traits: View<Traits>,
+        species: View<Species>,
         mut velocities: ViewMut<TargetVelocity>,
         mut speeds: ViewMut<TargetSpeed>,
         mut stress: ViewMut<Stress>,
         mut densities: ViewMut<Density>,
         mut socials: ViewMut<Social>,
         chunks: UniqueView<Chunks>,
+        sim_rng: UniqueView<SimRng>,
         cfg: UniqueView<Config>,
     ) {
-        let others_positions: HashMap<EntityId, Vec2> = collect_components!(positions);
-        let others_velocities: HashMap<EntityId, Vec2> = collect_components!(velocities);
-        // let others_speeds: HashMap<EntityId, Scalar> = collect_components!(speeds);
+        let boundary: Boundary = Boundary::from_config(&cfg);
+        let model: Box<dyn BehaviorModel> = <dyn BehaviorModel>::from_config(&cfg);
+        let interaction: Interaction = Interaction::from_config(&cfg);
 
-        (
+        let samples: Vec<Sample> = (
             &positions,
-            &mut velocities,
-            &mut speeds,
-            &mut stress,
-            &mut densities,
-            &mut socials,
+            &headings,
+            &keys,
+            &traits,
+            &species,
+            &velocities,
+            &speeds,
+            &stress,
         )
             .iter()
             .with_id()
-            .for_each(|(id, (pos, vel, speed, stress, density, social))| {
-                let mut neighbors: HashSet<EntityId> = chunks.load_chunk(&pos.0);
-                neighbors.remove(&id);
-
-                density.set(neighbors.len());
-                if density.value < SchoolingMechanism::MAX_NEIGHBORS {
-                    neighbors.extend(chunks.load_neighbors(&cfg, &pos.0));
-                }
-                neighbors.remove(&id);
-
-                density.set(neighbors.len());
-                if density.is_zero() {
-                    social.set_alone();
-                    speed.0.value = 50.0;
-                    stress.0.value = 0.1;
-                    return;
-                }
-                social.set_grouped();
-
-                let close_neighbors_count: usize = neighbors
-                    .iter()
-                    .filter(|&neighbor_id| {
-                        let neighbor_position: Vec2 = others_positions[neighbor_id];
-                        pos.0.distance(neighbor_position) <= cfg.alignment_radius
-                    })
-                    .count();
-                density.set(close_neighbors_count);
-
-                let mut algo: SchoolingMechanism = SchoolingMechanism::setup(
-                    pos.0,
-                    vel.0,
-                    speed.0,
-                    stress.0,
-                    neighbors!(neighbors, others_positions),
-                    neighbors!(neighbors, others_velocities),
-                    // neighbors!(neighbors, others_speeds),
-                    cfg.avoidance_radius,
-                    cfg.alignment_radius,
-                    cfg.attraction_radius,
-                );
-
-                if !algo.avoidance() {
-                    if !algo.alignment() {
-                        algo.attraction();
-                    }
-                }
-
-                algo.set_behavior(&mut vel.0, &mut speed.0, &mut stress.0);
-            });
+            .map(
+                |(id, (pos, heading, key, traits, species, vel, speed, stress))| Sample {
+                    id,
+                    key: key.0,
+                    position: pos.0,
+                    heading: heading.0,
+                    velocity: vel.0,
+                    speed: speed.0,
+                    stress: stress.0,
+                    traits: *traits,
+                    species: species.0,
+                },
+            )
+            .collect();
+
+        let slot_count: usize = samples
+            .iter()
+            .map(|sample| sample.id.index() as usize + 1)
+            .max()
+            .unwrap_or(0);
+        let mut slots: Vec<u32> = vec![NO_SLOT; slot_count];
+        for (slot, sample) in samples.iter().enumerate() {
+            slots[sample.id.index() as usize] = slot as u32;
+        }
+
+        let mut decisions: Vec<Decision> = Vec::with_capacity(samples.len());
+        samples
+            .par_iter()
+            .map_init(
+                || (Vec::new(), Vec::new(), InteractionScratch::default()),
+                |(neighbors, strangers, scratch), sample| {
+                    let close_neighbors_count: usize =
+                        Self::perceive(sample, &samples, &slots, neighbors, &chunks, &cfg);
+                    interaction.select(sample.position, neighbors, scratch);
+                    neighbors.sort_unstable_by_key(|neighbor| neighbor.id);
+                    Self::split_species(sample, &samples, &slots, neighbors, strangers, &cfg);
+                    let has_company: bool = Self::has_company(sample, neighbors, &cfg);
+
+                    let fish: Neighborhood = Neighborhood {
+                        position: sample.position,
+                        heading: sample.heading,
+                        velocity: sample.velocity,
+                        speed: sample.speed,
+                        stress: sample.stress,
+                        perception: sample.traits.perception,
+                        others: neighbors,
+                        boundary,
+                    };
+                    Self::decide(
+                        &fish,
+                        sample,
+                        strangers,
+                        close_neighbors_count,
+                        has_company,
+                        model.as_ref(),
+                        &sim_rng,
+                        &cfg.behavior,
+                    )
+                },
+            )
+            .collect_into_vec(&mut decisions);
+
+        for (sample, decision) in samples.iter().zip(&decisions) {
+            let Ok((mut vel, mut speed, mut stress, mut density, mut social)) = (
+                &mut velocities,
+                &mut speeds,
+                &mut stress,
+                &mut densities,
+                &mut socials,
+            )
+                .get(sample.id)
+            else {
+                continue;
+            };
+
+            vel.0 = decision.velocity;
+            speed.0 = decision.speed;
+            if cfg.stress_mode == StressMode::Rules {
+                stress.0 = decision.stress;
+            }
+            density.set(decision.density);
+            social.is_in_group = decision.is_in_group;
+        }
+    }
+
+    fn perceive(
+        sample: &Sample,
+        samples: &[Sample],
+        slots: &[u32],
+        neighbors: &mut Vec<Neighbor>,
+        chunks: &Chunks,
+        cfg: &Config,
+    ) -> usize {
+        let boundary: Boundary = Boundary::from_config(cfg);
+        let attraction_radius: f32 = cfg.attraction_radius * sample.traits.perception;
+        let alignment_radius: f32 = cfg.alignment_radius * sample.traits.perception;
+
+        neighbors.clear();
+        if cfg.interaction_mode == InteractionMode::Topological {
+            Self::perceive_nearest(sample, samples, slots, neighbors, chunks, cfg);
+        } else {
+            neighbors.extend(
+                chunks
+                    .load_radius(&sample.position, attraction_radius)
+                    .filter_map(|other| Self::neighbor(sample, other, samples, slots, cfg))
+                    .filter(|other| {
+                        boundary.distance(sample.position, other.position) <= attraction_radius
+                    }),
+            );
+        }
+
+        neighbors
+            .iter()
+            .filter(|other| boundary.distance(sample.position, other.position) <= alignment_radius)
+            .count()
+    }
+
+    // Topological neighbors are the nearest ones whatever their distance, so the search grows ring
+    // by ring until enough of them are closer than any unvisited chunk, or the world is exhausted
+    fn perceive_nearest(
+        sample: &Sample,
+        samples: &[Sample],
+        slots: &[u32],
+        neighbors: &mut Vec<Neighbor>,
+        chunks: &Chunks,
+        cfg: &Config,
+    ) {
+        let boundary: Boundary = Boundary::from_config(cfg);
+
+        for reach in 0.. {
+            neighbors.extend(
+                chunks
+                    .load_ring(&sample.position, reach)
+                    .filter_map(|other| Self::neighbor(sample, other, samples, slots, cfg)),
+            );
+
+            let clearance: f32 = chunks.clearance(reach);
+            let settled: usize = neighbors
+                .iter()
+                .filter(|other| boundary.distance(sample.position, other.position) <= clearance)
+                .count();
+            if settled >= cfg.topological_neighbors || chunks.covers(reach) {
+                return;
+            }
+        }
+    }
+
+    fn neighbor(
+        sample: &Sample,
+        other: EntityId,
+        samples: &[Sample],
+        slots: &[u32],
+        cfg: &Config,
+    ) -> Option<Neighbor> {
+        if other == sample.id {
+            return None;
+        }
+
+        let other: &Sample = Self::sample(other, samples, slots)?;
+        if cfg.interaction(sample.species, other.species) == SpeciesInteraction::Ignore {
+            return None;
+        }
+        Some(Neighbor {
+            id: other.id,
+            position: other.position,
+            velocity: other.velocity,
+        })
+    }
+
+    // Only neighbors within the attraction radius keep a fish from being alone, even when the
+    // topological search picked farther ones
+    fn has_company(sample: &Sample, neighbors: &[Neighbor], cfg: &Config) -> bool {
+        let boundary: Boundary = Boundary::from_config(cfg);
+        let attraction_radius: f32 = cfg.attraction_radius * sample.traits.perception;
+
+        neighbors
+            .iter()
+            .any(|other| boundary.distance(sample.position, other.position) <= attraction_radius)
+    }
+
+    #[inline]
+    fn sample<'a>(id: EntityId, samples: &'a [Sample], slots: &[u32]) -> Option<&'a Sample> {
+        let slot: u32 = *slots.get(id.index() as usize)?;
+        samples.get(slot as usize)
+    }
+
+    fn split_species(
+        sample: &Sample,
+        samples: &[Sample],
+        slots: &[u32],
+        neighbors: &mut Vec<Neighbor>,
+        strangers: &mut Vec<(SpeciesInteraction, Vec2)>,
+        cfg: &Config,
+    ) {
+        let boundary: Boundary = Boundary::from_config(cfg);
+
+        strangers.clear();
+        neighbors.retain(|neighbor| {
+            let rule: SpeciesInteraction = Self::sample(neighbor.id, samples, slots)
+                .map_or(SpeciesInteraction::Align, |other| {
+                    cfg.interaction(sample.species, other.species)
+                });
+            if rule != SpeciesInteraction::Align {
+                strangers.push((rule, boundary.unwrap(sample.position, neighbor.position)));
+            }
+            rule == SpeciesInteraction::Align
+        });
+    }
+
+    fn decide(
+        fish: &Neighborhood,
+        sample: &Sample,
+        strangers: &[(SpeciesInteraction, Vec2)],
+        close_neighbors_count: usize,
+        has_company: bool,
+        model: &dyn BehaviorModel,
+        sim_rng: &SimRng,
+        params: &BehaviorParams,
+    ) -> Decision {
+        let mut away: Vec2 = Vec2::ZERO;
+        let mut toward: Vec2 = Vec2::ZERO;
+        for &(rule, position) in strangers {
+            let offset: Vec2 = (position - fish.position).normalized();
+            match rule {
+                SpeciesInteraction::Avoid => away -= offset,
+                SpeciesInteraction::Attract => toward += offset,
+                _ => (),
+            }
+        }
+
+        if away != Vec2::ZERO {
+            return Decision {
+                velocity: away.normalized(),
+                speed: Scalar::new(params.avoidance_speed) * sample.traits.speed,
+                stress: Scalar::new(params.avoidance_stress),
+                density: close_neighbors_count,
+                is_in_group: has_company,
+            };
+        }
+
+        if !has_company && toward == Vec2::ZERO {
+            return Decision {
+                velocity: fish.velocity,
+                speed: Scalar::new(params.alone_speed) * sample.traits.speed,
+                stress: Scalar::new(params.alone_stress),
+                density: 0,
+                is_in_group: false,
+            };
+        }
+
+        let behavior: Behavior = if !has_company {
+            Behavior {
+                velocity: toward.normalized(),
+                speed: Scalar::new(params.attraction_speed),
+                stress: Scalar::new(params.attraction_stress),
+            }
+        } else {
+            let mut rng: StreamRng = sim_rng.stream(sample.key, SimRng::SWARMING);
+            model.decide(fish, &mut rng)
+        };
+
+        let mut velocity: Vec2 = behavior.velocity;
+        if toward != Vec2::ZERO {
+            let steered: Vec2 = (velocity + toward.normalized()).normalized();
+            if steered != Vec2::ZERO {
+                velocity = steered;
+            }
+        }
+
+        if sample.traits.sociability < 1.0 {
+            let blended: Vec2 = fish
+                .velocity
+                .lerp(velocity, sample.traits.sociability)
+                .normalized();
+            if blended != Vec2::ZERO {
+                velocity = blended;
+            }
+        }
+
+        Decision {
+            velocity,
+            speed: behavior.speed * sample.traits.speed,
+            stress: behavior.stress,
+            density: close_neighbors_count,
+            is_in_group: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Sample, Swarming};
+    use crate::{
+        algo::{Interaction, InteractionScratch, Neighbor},
+        BoundaryMode, Chunks, Config, Density, InteractionMode, Position, RngKey, Scalar, SimRng,
+        Social, Species, Stress, TargetSpeed, TargetVelocity, Traits, Vec2, Velocity,
+    };
+    use shipyard::{EntityId, IntoIter, View, World};
+
+    fn samples(positions: &[Vec2]) -> Vec<Sample> {
+        positions
+            .iter()
+            .enumerate()
+            .map(|(index, &position)| Sample {
+                id: EntityId::new_from_index_and_gen(index as u64, 0),
+                key: index as u64,
+                position,
+                heading: Vec2::new(1.0, 0.0),
+                velocity: Vec2::new(1.0, 0.0),
+                speed: Scalar::new(1.0),
+                stress: Scalar::new(0.0),
+                traits: Traits::default(),
+                species: 0,
+            })
+            .collect()
+    }
+
+    fn swarm(cfg: Config, fish: &[(Vec2, Vec2, Vec2)]) -> World {
+        let mut world: World = World::new();
+        let mut chunks: Chunks = Chunks::new(&cfg);
+        for (index, &(position, heading, target)) in fish.iter().enumerate() {
+            let id: EntityId = world.add_entity((
+                Position(position),
+                Velocity(heading),
+                RngKey(index as u64),
+                Traits::default(),
+                Species(0),
+                TargetVelocity(target),
+                TargetSpeed(Scalar::new(1.0)),
+                Stress(Scalar::ZERO),
+                Density::default(),
+                Social::default(),
+            ));
+            chunks.update(id, &position);
+        }
+        world.add_unique(chunks);
+        world.add_unique(SimRng::new(Some(42)));
+        world.add_unique(cfg);
+
+        world.run(Swarming::system);
+        world
+    }
+
+    #[test]
+    fn swarming_topological_reaches_beyond_attraction_radius() {
+        let cfg: Config = Config {
+            width: 1000,
+            height: 1000,
+            boundary_mode: BoundaryMode::Reflect,
+            interaction_mode: InteractionMode::Topological,
+            topological_neighbors: 3,
+            ..Config::default()
+        };
+        let samples: Vec<Sample> = samples(&[
+            Vec2::new(500.0, 500.0),
+            Vec2::new(620.0, 500.0),
+            Vec2::new(500.0, 370.0),
+            Vec2::new(360.0, 500.0),
+            Vec2::new(900.0, 900.0),
+            Vec2::new(20.0, 20.0),
+        ]);
+        let slots: Vec<u32> = (0..samples.len() as u32).collect();
+        assert!(cfg.attraction_radius * samples[0].traits.perception < 120.0);
+
+        let mut chunks: Chunks = Chunks::new(&cfg);
+        for sample in &samples {
+            chunks.update(sample.id, &sample.position);
+        }
+
+        let mut neighbors: Vec<Neighbor> = Vec::new();
+        Swarming::perceive(&samples[0], &samples, &slots, &mut neighbors, &chunks, &cfg);
+        Interaction::from_config(&cfg).select(
+            samples[0].position,
+            &mut neighbors,
+            &mut InteractionScratch::default(),
+        );
+
+        let mut ids: Vec<u64> = neighbors.iter().map(|other| other.id.index()).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![1, 2, 3]);
+
+        let lonely: Vec<Sample> = samples[..1].to_vec();
+        let mut chunks: Chunks = Chunks::new(&cfg);
+        chunks.update(lonely[0].id, &lonely[0].position);
+        Swarming::perceive(&lonely[0], &lonely, &[0], &mut neighbors, &chunks, &cfg);
+        assert!(neighbors.is_empty());
+    }
+
+    #[test]
+    fn swarming_isolated_fish_stays_alone_in_every_mode() {
+        for interaction_mode in [
+            InteractionMode::Metric,
+            InteractionMode::Topological,
+            InteractionMode::Voronoi,
+        ] {
+            let cfg: Config = Config {
+                width: 1000,
+                height: 1000,
+                boundary_mode: BoundaryMode::Reflect,
+                interaction_mode,
+                ..Config::default()
+            };
+            let east: Vec2 = Vec2::new(1.0, 0.0);
+            let world: World = swarm(
+                cfg,
+                &[
+                    (Vec2::new(100.0, 100.0), east, east),
+                    (Vec2::new(800.0, 800.0), east, east),
+                    (Vec2::new(810.0, 800.0), east, east),
+                ],
+            );
+
+            let grouped: Vec<bool> =
+                world.run(|positions: View<Position>, socials: View<Social>| {
+                    let mut grouped: Vec<(f32, bool)> = (&positions, &socials)
+                        .iter()
+                        .map(|(pos, social)| (pos.0.x, social.is_in_group))
+                        .collect();
+                    grouped.sort_by(|a, b| a.0.total_cmp(&b.0));
+                    grouped
+                        .into_iter()
+                        .map(|(_, is_in_group)| is_in_group)
+                        .collect()
+                });
+            assert_eq!(grouped, vec![false, true, true], "{interaction_mode:?}");
+
+            let speeds: Vec<f32> =
+                world.run(|speeds: View<TargetSpeed>, positions: View<Position>| {
+                    (&positions, &speeds)
+                        .iter()
+                        .filter(|(pos, _)| pos.0.x < 500.0)
+                        .map(|(_, speed)| speed.0.value)
+                        .collect()
+                });
+            assert_eq!(
+                speeds,
+                vec![cfg.behavior.alone_speed * Traits::default().speed],
+                "{interaction_mode:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn swarming_field_of_view_follows_heading() {
+        let cfg: Config = Config {
+            width: 1000,
+            height: 1000,
+            boundary_mode: BoundaryMode::Reflect,
+            alignment_fov: 180.0,
+            ..Config::default()
+        };
+        let east: Vec2 = Vec2::new(1.0, 0.0);
+        let north: Vec2 = Vec2::new(0.0, 1.0);
+
+        for (heading, expected) in [(east, north), (Vec2::new(-1.0, 0.0), east)] {
+            let world: World = swarm(
+                cfg,
+                &[
+                    (Vec2::new(500.0, 500.0), heading, east),
+                    (Vec2::new(520.0, 500.0), north, north),
+                ],
+            );
+
+            let target: Vec2 =
+                world.run(|positions: View<Position>, targets: View<TargetVelocity>| {
+                    (&positions, &targets)
+                        .iter()
+                        .find(|(pos, _)| pos.0.x < 510.0)
+                        .map(|(_, target)| target.0)
+                        .unwrap()
+                });
+            assert!(target.distance(expected) < 1e-5, "{heading:?}: {target:?}");
+        }
     }
 }
@@ -42,6 +42,18 @@ impl Angle {
         }
     }
 
+    #[inline]
+    pub fn from_degrees(degrees: f32) -> Self {
+        Self {
+            radians: degrees.to_radians(),
+        }
+    }
+
+    #[inline]
+    pub fn to_degrees(self) -> f32 {
+        self.radians.to_degrees()
+    }
+
     #[inline]
     pub fn from_vector(vec: Vec2) -> Self {
         Self {
@@ -235,6 +247,14 @@ mod tests {
         assert!((from_vec.radians - PI / 2.0).abs() < 1e-6);
     }
 
+    #[test]
+    fn angle_degrees() {
+        let angle: Angle = Angle::from_degrees(180.0);
+
+        assert!((angle.radians - PI).abs() < 1e-6);
+        assert!((Angle::HALF.to_degrees() - 180.0).abs() < 1e-4);
+    }
+
     #[test]
     fn angle_lerp_shortest_path() {
         let start: Angle = Angle::new(0.1);
@@ -251,6 +271,6 @@ mod tests {
 
         let angle: Angle = Angle::new_random(&mut rng, 0.0f32..PI);
 
-        assert!(angle.radians >= 0.0 && angle.radians < PI);
+        assert!((0.0..PI).contains(&angle.radians));
     }
 }
@@ -0,0 +1,152 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{BoundaryMode, Config, Vec2};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Boundary {
+    pub mode: BoundaryMode,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Boundary {
+    #[inline]
+    pub fn new(mode: BoundaryMode, width: f32, height: f32) -> Self {
+        Self {
+            mode,
+            width,
+            height,
+        }
+    }
+
+    pub fn from_config(cfg: &Config) -> Self {
+        Self::new(cfg.boundary_mode, cfg.width as f32, cfg.height as f32)
+    }
+
+    #[inline]
+    pub fn is_periodic(self) -> bool {
+        self.mode == BoundaryMode::Wrap
+    }
+
+    pub fn offset(self, from: Vec2, to: Vec2) -> Vec2 {
+        let offset: Vec2 = to - from;
+
+        if !self.is_periodic() {
+            return offset;
+        }
+
+        Vec2::new(
+            Self::minimal_image(offset.x, self.width),
+            Self::minimal_image(offset.y, self.height),
+        )
+    }
+
+    #[inline]
+    pub fn distance(self, a: Vec2, b: Vec2) -> f32 {
+        self.offset(a, b).length()
+    }
+
+    #[inline]
+    pub fn unwrap(self, origin: Vec2, other: Vec2) -> Vec2 {
+        origin + self.offset(origin, other)
+    }
+
+    pub fn wrap(self, pos: Vec2) -> Vec2 {
+        Vec2::new(
+            Self::wrap_coord(pos.x, self.width),
+            Self::wrap_coord(pos.y, self.height),
+        )
+    }
+
+    pub fn clamp(self, pos: Vec2) -> Vec2 {
+        Vec2::new(
+            pos.x.clamp(0.0, self.width.next_down()),
+            pos.y.clamp(0.0, self.height.next_down()),
+        )
+    }
+
+    #[inline]
+    fn minimal_image(delta: f32, size: f32) -> f32 {
+        if size <= 0.0 {
+            return delta;
+        }
+        delta - size * (delta / size).round()
+    }
+
+    #[inline]
+    fn wrap_coord(value: f32, size: f32) -> f32 {
+        let wrapped: f32 = value.rem_euclid(size);
+        if wrapped >= size {
+            0.0
+        } else {
+            wrapped
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Boundary;
+    use crate::{BoundaryMode, Vec2};
+
+    #[test]
+    fn boundary_wrap_offset_crosses_seam() {
+        let boundary: Boundary = Boundary::new(BoundaryMode::Wrap, 100.0, 50.0);
+
+        assert_eq!(
+            boundary.offset(Vec2::new(2.0, 1.0), Vec2::new(98.0, 49.0)),
+            Vec2::new(-4.0, -2.0)
+        );
+        assert_eq!(
+            boundary.distance(Vec2::new(99.0, 25.0), Vec2::new(1.0, 25.0)),
+            2.0
+        );
+        assert_eq!(
+            boundary.unwrap(Vec2::new(99.0, 25.0), Vec2::new(1.0, 25.0)),
+            Vec2::new(101.0, 25.0)
+        );
+    }
+
+    #[test]
+    fn boundary_walls_offset_is_direct() {
+        for mode in [BoundaryMode::Reflect, BoundaryMode::SoftWall] {
+            let boundary: Boundary = Boundary::new(mode, 100.0, 50.0);
+            assert_eq!(
+                boundary.offset(Vec2::new(2.0, 1.0), Vec2::new(98.0, 49.0)),
+                Vec2::new(96.0, 48.0)
+            );
+        }
+    }
+
+    #[test]
+    fn boundary_wrap_position() {
+        let boundary: Boundary = Boundary::new(BoundaryMode::Wrap, 100.0, 50.0);
+
+        assert_eq!(boundary.wrap(Vec2::new(-1.0, 50.0)), Vec2::new(99.0, 0.0));
+        assert_eq!(boundary.wrap(Vec2::new(100.5, 10.0)), Vec2::new(0.5, 10.0));
+        assert_eq!(boundary.wrap(Vec2::new(-1e-9, 10.0)).x, 0.0);
+    }
+
+    #[test]
+    fn boundary_clamp_position() {
+        let boundary: Boundary = Boundary::new(BoundaryMode::SoftWall, 100.0, 50.0);
+        let clamped: Vec2 = boundary.clamp(Vec2::new(120.0, -3.0));
+
+        assert!(clamped.x < 100.0 && clamped.x > 99.9);
+        assert_eq!(clamped.y, 0.0);
+    }
+}
@@ -15,11 +15,15 @@
  */
 
 mod angle;
+mod boundary;
+mod obstacle;
 mod radius;
 mod scalar;
 mod vec2;
 
 pub use angle::Angle;
+pub use boundary::Boundary;
+pub use obstacle::Obstacle;
 pub use radius::Radius;
 pub use scalar::Scalar;
 pub use vec2::Vec2;
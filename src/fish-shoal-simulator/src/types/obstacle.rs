@@ -0,0 +1,316 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::Vec2;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const EPSILON: f32 = 1e-6;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Obstacle {
+    Circle { center: Vec2, radius: f32 },
+    Rect { min: Vec2, max: Vec2 },
+    Polygon { points: Vec<Vec2> },
+}
+
+impl Obstacle {
+    pub fn circle(center: Vec2, radius: f32) -> Self {
+        Self::Circle {
+            center,
+            radius: radius.abs(),
+        }
+    }
+
+    pub fn rect(corner: Vec2, opposite: Vec2) -> Self {
+        Self::Rect {
+            min: Vec2::new(corner.x.min(opposite.x), corner.y.min(opposite.y)),
+            max: Vec2::new(corner.x.max(opposite.x), corner.y.max(opposite.y)),
+        }
+    }
+
+    pub fn polygon(points: Vec<Vec2>) -> Self {
+        Self::Polygon { points }
+    }
+
+    pub fn center(&self) -> Vec2 {
+        match self {
+            Self::Circle { center, .. } => *center,
+            Self::Rect { min, max } => (*min + *max) / 2.0,
+            Self::Polygon { points } if points.is_empty() => Vec2::ZERO,
+            Self::Polygon { points } => {
+                points.iter().fold(Vec2::ZERO, |acc, &p| acc + p) / points.len() as f32
+            }
+        }
+    }
+
+    pub fn contains(&self, point: Vec2) -> bool {
+        match self {
+            Self::Circle { center, radius } => point.distance_squared(*center) < radius * radius,
+            Self::Rect { min, max } => {
+                point.x > min.x && point.x < max.x && point.y > min.y && point.y < max.y
+            }
+            Self::Polygon { points } => {
+                if points.len() < 3 {
+                    return false;
+                }
+
+                let mut inside: bool = false;
+                for (a, b) in Self::edges(points) {
+                    if (a.y > point.y) != (b.y > point.y)
+                        && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x
+                    {
+                        inside = !inside;
+                    }
+                }
+                inside
+            }
+        }
+    }
+
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        match self {
+            Self::Circle { center, radius } => {
+                let offset: Vec2 = point - *center;
+                if offset.length_squared() < EPSILON {
+                    return *center + Vec2::new(*radius, 0.0);
+                }
+                *center + offset.normalized() * *radius
+            }
+            Self::Rect { min, max } => {
+                if !self.contains(point) {
+                    let mut clamped: Vec2 = point;
+                    clamped.clamp(*min, *max);
+                    return clamped;
+                }
+
+                let to_left: f32 = point.x - min.x;
+                let to_right: f32 = max.x - point.x;
+                let to_top: f32 = point.y - min.y;
+                let to_bottom: f32 = max.y - point.y;
+                let nearest: f32 = to_left.min(to_right).min(to_top).min(to_bottom);
+
+                if nearest == to_left {
+                    Vec2::new(min.x, point.y)
+                } else if nearest == to_right {
+                    Vec2::new(max.x, point.y)
+                } else if nearest == to_top {
+                    Vec2::new(point.x, min.y)
+                } else {
+                    Vec2::new(point.x, max.y)
+                }
+            }
+            Self::Polygon { points } => Self::edges(points)
+                .map(|(a, b)| Self::closest_on_segment(point, a, b))
+                .min_by(|a, b| {
+                    a.distance_squared(point)
+                        .total_cmp(&b.distance_squared(point))
+                })
+                .unwrap_or(point),
+        }
+    }
+
+    pub fn signed_distance(&self, point: Vec2) -> f32 {
+        let distance: f32 = point.distance(self.closest_point(point));
+        if self.contains(point) {
+            -distance
+        } else {
+            distance
+        }
+    }
+
+    pub fn outward_normal(&self, point: Vec2) -> Vec2 {
+        let closest: Vec2 = self.closest_point(point);
+        let offset: Vec2 = point - closest;
+
+        if offset.length_squared() < EPSILON {
+            return (closest - self.center()).normalized();
+        }
+
+        if self.contains(point) {
+            -offset.normalized()
+        } else {
+            offset.normalized()
+        }
+    }
+
+    pub fn push_out(&self, point: Vec2, margin: f32) -> Option<Vec2> {
+        if self.signed_distance(point) >= margin {
+            return None;
+        }
+
+        Some(self.closest_point(point) + self.outward_normal(point) * margin)
+    }
+
+    pub fn ray_hit(&self, origin: Vec2, dir: Vec2, max_distance: f32) -> Option<f32> {
+        if self.contains(origin) {
+            return Some(0.0);
+        }
+
+        let hit: Option<f32> = match self {
+            Self::Circle { center, radius } => {
+                let to_origin: Vec2 = origin - *center;
+                let b: f32 = to_origin.dot(dir);
+                let c: f32 = to_origin.length_squared() - radius * radius;
+                let discriminant: f32 = b * b - c;
+
+                if discriminant < 0.0 {
+                    None
+                } else {
+                    Some(-b - discriminant.sqrt()).filter(|&t| t >= 0.0)
+                }
+            }
+            Self::Rect { min, max } => {
+                let corners: [Vec2; 4] =
+                    [*min, Vec2::new(max.x, min.y), *max, Vec2::new(min.x, max.y)];
+                Self::ray_polygon(origin, dir, &corners)
+            }
+            Self::Polygon { points } => Self::ray_polygon(origin, dir, points),
+        };
+
+        hit.filter(|&t| t <= max_distance)
+    }
+
+    fn ray_polygon(origin: Vec2, dir: Vec2, points: &[Vec2]) -> Option<f32> {
+        Self::edges(points)
+            .filter_map(|(a, b)| Self::ray_segment(origin, dir, a, b))
+            .min_by(f32::total_cmp)
+    }
+
+    fn ray_segment(origin: Vec2, dir: Vec2, a: Vec2, b: Vec2) -> Option<f32> {
+        let edge: Vec2 = b - a;
+        let denominator: f32 = Self::cross(dir, edge);
+        if denominator.abs() < EPSILON {
+            return None;
+        }
+
+        let to_start: Vec2 = a - origin;
+        let t: f32 = Self::cross(to_start, edge) / denominator;
+        let u: f32 = Self::cross(to_start, dir) / denominator;
+
+        (t >= 0.0 && (0.0..=1.0).contains(&u)).then_some(t)
+    }
+
+    fn closest_on_segment(point: Vec2, a: Vec2, b: Vec2) -> Vec2 {
+        let edge: Vec2 = b - a;
+        let length_squared: f32 = edge.length_squared();
+        if length_squared < EPSILON {
+            return a;
+        }
+
+        let t: f32 = ((point - a).dot(edge) / length_squared).clamp(0.0, 1.0);
+        a + edge * t
+    }
+
+    fn edges(points: &[Vec2]) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(&a, &b)| (a, b))
+    }
+
+    #[inline]
+    fn cross(a: Vec2, b: Vec2) -> f32 {
+        a.x * b.y - a.y * b.x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Obstacle;
+    use crate::Vec2;
+
+    fn triangle() -> Obstacle {
+        Obstacle::polygon(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(10.0, 0.0),
+            Vec2::new(0.0, 10.0),
+        ])
+    }
+
+    #[test]
+    fn obstacle_contains() {
+        let circle: Obstacle = Obstacle::circle(Vec2::new(5.0, 5.0), 2.0);
+        assert!(circle.contains(Vec2::new(6.0, 5.0)));
+        assert!(!circle.contains(Vec2::new(8.0, 5.0)));
+
+        let rect: Obstacle = Obstacle::rect(Vec2::new(10.0, 10.0), Vec2::new(0.0, 0.0));
+        assert!(rect.contains(Vec2::new(3.0, 7.0)));
+        assert!(!rect.contains(Vec2::new(11.0, 7.0)));
+
+        assert!(triangle().contains(Vec2::new(2.0, 2.0)));
+        assert!(!triangle().contains(Vec2::new(8.0, 8.0)));
+    }
+
+    #[test]
+    fn obstacle_closest_point() {
+        let circle: Obstacle = Obstacle::circle(Vec2::ZERO, 2.0);
+        assert_eq!(
+            circle.closest_point(Vec2::new(5.0, 0.0)),
+            Vec2::new(2.0, 0.0)
+        );
+
+        let rect: Obstacle = Obstacle::rect(Vec2::ZERO, Vec2::new(10.0, 10.0));
+        assert_eq!(rect.closest_point(Vec2::new(1.0, 5.0)), Vec2::new(0.0, 5.0));
+        assert_eq!(
+            rect.closest_point(Vec2::new(15.0, 5.0)),
+            Vec2::new(10.0, 5.0)
+        );
+
+        assert_eq!(
+            triangle().closest_point(Vec2::new(5.0, -3.0)),
+            Vec2::new(5.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn obstacle_signed_distance() {
+        let rect: Obstacle = Obstacle::rect(Vec2::ZERO, Vec2::new(10.0, 10.0));
+        assert_eq!(rect.signed_distance(Vec2::new(2.0, 5.0)), -2.0);
+        assert_eq!(rect.signed_distance(Vec2::new(13.0, 5.0)), 3.0);
+    }
+
+    #[test]
+    fn obstacle_push_out() {
+        let circle: Obstacle = Obstacle::circle(Vec2::ZERO, 2.0);
+        let pushed: Vec2 = circle.push_out(Vec2::new(1.0, 0.0), 0.5).unwrap();
+        assert!((pushed.x - 2.5).abs() < 1e-5 && pushed.y.abs() < 1e-5);
+        assert_eq!(circle.push_out(Vec2::new(5.0, 0.0), 0.5), None);
+
+        let pushed: Vec2 = triangle().push_out(Vec2::new(1.0, 4.0), 0.1).unwrap();
+        assert!(!triangle().contains(pushed));
+    }
+
+    #[test]
+    fn obstacle_ray_hit() {
+        let dir: Vec2 = Vec2::new(1.0, 0.0);
+
+        let circle: Obstacle = Obstacle::circle(Vec2::new(10.0, 0.0), 2.0);
+        assert_eq!(circle.ray_hit(Vec2::ZERO, dir, 100.0), Some(8.0));
+        assert_eq!(circle.ray_hit(Vec2::ZERO, -dir, 100.0), None);
+        assert_eq!(circle.ray_hit(Vec2::ZERO, dir, 5.0), None);
+
+        let rect: Obstacle = Obstacle::rect(Vec2::new(5.0, -1.0), Vec2::new(7.0, 1.0));
+        assert_eq!(rect.ray_hit(Vec2::ZERO, dir, 100.0), Some(5.0));
+        assert_eq!(rect.ray_hit(Vec2::new(6.0, 0.0), dir, 100.0), Some(0.0));
+
+        assert_eq!(
+            triangle().ray_hit(Vec2::new(-4.0, 2.0), dir, 100.0),
+            Some(4.0)
+        );
+    }
+}
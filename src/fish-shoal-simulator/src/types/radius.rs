@@ -190,6 +190,6 @@ mod tests {
         let random_radius: Radius = Radius::new_random(&mut rng, 10.0f32..20.0f32);
 
         let value: f32 = random_radius.value;
-        assert!(value >= 10.0 && value < 20.0);
+        assert!((10.0..20.0).contains(&value));
     }
 }
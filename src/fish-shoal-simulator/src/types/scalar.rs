@@ -234,6 +234,6 @@ mod tests {
 
         let scalar: Scalar = Scalar::new_random(&mut rng, 0.0f32..1.0f32);
 
-        assert!(scalar.value >= 0.0 && scalar.value < 1.0);
+        assert!((0.0..1.0).contains(&scalar.value));
     }
 }
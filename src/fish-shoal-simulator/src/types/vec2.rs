@@ -15,6 +15,8 @@
  */
 
 use rand::Rng;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::{
     f32::consts::TAU,
     fmt::{self, Display, Formatter},
@@ -22,6 +24,7 @@ use std::{
 };
 
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Vec2 {
     pub x: f32,
     pub y: f32,
@@ -84,7 +87,11 @@ impl Vec2 {
     #[inline]
     pub fn normalized(self) -> Self {
         let len: f32 = self.length();
-        if len > 0.0 { self / len } else { Self::ZERO }
+        if len > 0.0 {
+            self / len
+        } else {
+            Self::ZERO
+        }
     }
 
     #[inline]
@@ -263,7 +270,7 @@ mod tests {
 
         let vec: Vec2 = Vec2::new_random(&mut rng, 0.0f32..10.0f32, 0.0f32..10.0f32);
 
-        assert!(vec.x >= 0.0 && vec.x < 10.0);
-        assert!(vec.y >= 0.0 && vec.y < 10.0);
+        assert!((0.0..10.0).contains(&vec.x));
+        assert!((0.0..10.0).contains(&vec.y));
     }
 }
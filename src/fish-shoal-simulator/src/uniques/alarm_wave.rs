@@ -0,0 +1,113 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use crate::Vec2;
+use shipyard::Unique;
+
+#[derive(Unique, Debug, Default, Copy, Clone)]
+pub struct AlarmWave {
+    is_active: bool,
+    origin: Vec2,
+    elapsed: f32,
+    reached: usize,
+    distance: f32,
+    arrival: f32,
+    speed: f32,
+}
+
+impl AlarmWave {
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    pub fn start(&mut self, origin: Vec2) {
+        *self = Self {
+            is_active: true,
+            origin,
+            reached: 1,
+            ..Self::default()
+        };
+    }
+
+    pub fn advance(&mut self, dt: f32) {
+        if self.is_active {
+            self.elapsed += dt;
+        }
+    }
+
+    pub fn reach(&mut self, distance: f32) {
+        if !self.is_active {
+            return;
+        }
+
+        self.reached += 1;
+        if distance > self.distance {
+            self.distance = distance;
+            self.arrival = self.elapsed;
+            self.speed = if self.arrival > 0.0 {
+                self.distance / self.arrival
+            } else {
+                0.0
+            };
+        }
+    }
+
+    pub fn finish(&mut self) {
+        self.is_active = false;
+    }
+
+    pub fn origin(&self) -> Vec2 {
+        self.origin
+    }
+
+    pub fn reached(&self) -> usize {
+        self.reached
+    }
+
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AlarmWave;
+    use crate::Vec2;
+
+    #[test]
+    fn alarm_wave_measures_reach_and_speed() {
+        let mut wave: AlarmWave = AlarmWave::default();
+        wave.reach(10.0);
+        assert_eq!(wave.reached(), 0);
+
+        wave.start(Vec2::ZERO);
+        wave.advance(0.5);
+        wave.reach(20.0);
+        wave.advance(0.5);
+        wave.reach(40.0);
+        wave.reach(30.0);
+        wave.finish();
+        wave.advance(1.0);
+
+        assert!(!wave.is_active());
+        assert_eq!(wave.reached(), 4);
+        assert_eq!(wave.distance(), 40.0);
+        assert_eq!(wave.speed(), 40.0);
+    }
+}
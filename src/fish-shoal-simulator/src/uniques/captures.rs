@@ -0,0 +1,35 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use shipyard::{EntityId, Unique};
+use std::mem;
+
+#[derive(Unique, Debug, Default)]
+pub struct Captures {
+    captured: Vec<EntityId>,
+}
+
+impl Captures {
+    pub fn push(&mut self, id: EntityId) {
+        if !self.captured.contains(&id) {
+            self.captured.push(id);
+        }
+    }
+
+    pub fn take(&mut self) -> Vec<EntityId> {
+        mem::take(&mut self.captured)
+    }
+}
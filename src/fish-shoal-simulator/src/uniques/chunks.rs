@@ -14,110 +14,271 @@
  * limitations under the License.
  */
 
-use crate::{Config, Vec2};
+use crate::{Boundary, Config, Vec2};
 use shipyard::{EntityId, Unique};
-use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Default, Copy, Clone)]
+struct Slot {
+    id: Option<EntityId>,
+    cell: usize,
+    offset: usize,
+    tick: u32,
+}
 
 #[derive(Unique, Debug, Default)]
 pub struct Chunks {
     chunk_size: f32,
-    chunks: HashMap<u32, HashSet<EntityId>>,
+    cell_width: f32,
+    cell_height: f32,
+    columns: usize,
+    rows: usize,
+    periodic: bool,
+    cells: Vec<Vec<EntityId>>,
+    slots: Vec<Slot>,
+    tick: u32,
+    len: usize,
 }
 
 impl Chunks {
-    pub fn new(chunk_size: f32) -> Self {
-        Self {
-            chunk_size,
-            chunks: HashMap::new(),
-        }
+    pub fn new(cfg: &Config) -> Self {
+        let mut chunks: Self = Self::default();
+        chunks.configure(cfg);
+        chunks
     }
 
-    pub fn clear(&mut self) {
-        self.chunks.clear();
+    pub fn chunk_size_for(cfg: &Config) -> f32 {
+        cfg.attraction_radius
+            .max(cfg.alignment_radius)
+            .max(cfg.avoidance_radius)
+            .max(1.0)
+    }
+
+    pub fn chunk_size(&self) -> f32 {
+        self.chunk_size
     }
 
-    pub fn resize(&mut self, chunk_size: f32) {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn configure(&mut self, cfg: &Config) -> bool {
+        let chunk_size: f32 = Self::chunk_size_for(cfg);
+        let periodic: bool = Boundary::from_config(cfg).is_periodic();
+        let (columns, cell_width): (usize, f32) =
+            Self::tiling(cfg.width as f32, chunk_size, periodic);
+        let (rows, cell_height): (usize, f32) =
+            Self::tiling(cfg.height as f32, chunk_size, periodic);
+
+        if chunk_size == self.chunk_size
+            && cell_width == self.cell_width
+            && cell_height == self.cell_height
+            && columns == self.columns
+            && rows == self.rows
+            && periodic == self.periodic
+        {
+            return false;
+        }
+
         self.chunk_size = chunk_size;
+        self.cell_width = cell_width;
+        self.cell_height = cell_height;
+        self.columns = columns;
+        self.rows = rows;
+        self.periodic = periodic;
+        self.cells.resize_with(columns * rows, Vec::new);
+        self.cells.truncate(columns * rows);
+        self.clear();
+        true
     }
 
-    pub fn store(&mut self, pos: &Vec2, id: EntityId) {
-        let chunk_id: u32 = self.chunk_id_from_pos(pos);
+    pub fn clear(&mut self) {
+        self.cells.iter_mut().for_each(Vec::clear);
+        self.slots
+            .iter_mut()
+            .for_each(|slot| *slot = Slot::default());
+        self.len = 0;
+    }
 
-        let is_unique: bool = self.chunks.entry(chunk_id).or_default().insert(id);
-        debug_assert!(is_unique, "Entity {id:?} already exists in chunk");
+    pub fn begin_update(&mut self) {
+        self.tick = self.tick.wrapping_add(1);
     }
 
-    pub fn remove(&mut self, pos: &Vec2, id: EntityId) {
-        let chunk_id: u32 = self.chunk_id_from_pos(pos);
+    pub fn update(&mut self, id: EntityId, pos: &Vec2) {
+        let cell: usize = self.cell_from_pos(pos);
+        let idx: usize = id.index() as usize;
 
-        if let Some(chunk) = self.chunks.get_mut(&chunk_id) {
-            let existed: bool = chunk.remove(&id);
-            debug_assert!(existed, "Entity {id:?} was not in chunk");
+        if idx >= self.slots.len() {
+            self.slots.resize(idx + 1, Slot::default());
+        }
 
-            if chunk.is_empty() {
-                self.chunks.remove(&chunk_id);
-            }
+        let slot: Slot = self.slots[idx];
+        if slot.id == Some(id) && slot.cell == cell {
+            self.slots[idx].tick = self.tick;
+            return;
         }
+
+        if slot.id.is_some() {
+            self.remove_slot(idx);
+        }
+
+        self.cells[cell].push(id);
+        self.slots[idx] = Slot {
+            id: Some(id),
+            cell,
+            offset: self.cells[cell].len() - 1,
+            tick: self.tick,
+        };
+        self.len += 1;
     }
 
-    pub fn load_chunk(&self, pos: &Vec2) -> HashSet<EntityId> {
-        let id: u32 = self.chunk_id_from_pos(pos);
+    pub fn remove(&mut self, id: EntityId) {
+        let idx: usize = id.index() as usize;
 
-        if let Some(chunk) = self.chunks.get(&id) {
-            return chunk.clone();
+        if self.slots.get(idx).is_some_and(|slot| slot.id == Some(id)) {
+            self.remove_slot(idx);
         }
+    }
 
-        HashSet::new()
+    pub fn sweep(&mut self) {
+        for idx in 0..self.slots.len() {
+            let slot: Slot = self.slots[idx];
+            if slot.id.is_some() && slot.tick != self.tick {
+                self.remove_slot(idx);
+            }
+        }
     }
 
-    pub fn load_neighbors(&self, cfg: &Config, pos: &Vec2) -> HashSet<EntityId> {
-        let (chunk_x, chunk_y): (u32, u32) = self.chunk_coords(pos);
+    pub fn load_chunk(&self, pos: &Vec2) -> &[EntityId] {
+        if self.cells.is_empty() {
+            return &[];
+        }
+        &self.cells[self.cell_from_pos(pos)]
+    }
 
-        let region_width: f32 = (cfg.width as f32 / self.chunk_size).floor();
-        let region_height: f32 = (cfg.height as f32 / self.chunk_size).floor();
+    pub fn load_neighbors(&self, pos: &Vec2) -> impl Iterator<Item = EntityId> + '_ {
+        self.collect_around(pos, (1, 1), Some(0))
+    }
 
-        let mut neighbors: HashSet<EntityId> = HashSet::new();
+    pub fn load_area(&self, pos: &Vec2, reach: usize) -> impl Iterator<Item = EntityId> + '_ {
+        self.collect_around(pos, (reach, reach), None)
+    }
 
-        for dx in -1..=1 {
-            for dy in -1..=1 {
-                if dx == 0 && dy == 0 {
-                    continue;
-                }
+    pub fn load_ring(&self, pos: &Vec2, reach: usize) -> impl Iterator<Item = EntityId> + '_ {
+        self.collect_around(pos, (reach, reach), reach.checked_sub(1))
+    }
 
-                let x: f32 = (chunk_x as i32 + dx) as f32 % region_width;
-                let y: f32 = (chunk_y as i32 + dy) as f32 % region_height;
+    pub fn load_radius(&self, pos: &Vec2, radius: f32) -> impl Iterator<Item = EntityId> + '_ {
+        let reach_x: usize = (radius / self.cell_width).ceil().max(0.0) as usize;
+        let reach_y: usize = (radius / self.cell_height).ceil().max(0.0) as usize;
+        self.collect_around(pos, (reach_x, reach_y), None)
+    }
 
-                let chunk_id: u32 = Self::chunk_id_from_coords(x as u32, y as u32);
+    pub fn covers(&self, reach: usize) -> bool {
+        let len: usize = self.columns.max(self.rows);
+        if self.periodic {
+            2 * reach + 1 >= len
+        } else {
+            reach + 1 >= len
+        }
+    }
 
-                if let Some(chunk) = self.chunks.get(&chunk_id) {
-                    neighbors.extend(chunk);
-                }
-            }
+    pub fn clearance(&self, reach: usize) -> f32 {
+        reach as f32 * self.cell_width.min(self.cell_height)
+    }
+
+    // Periodic worlds are tiled exactly, with cells at least as large as the chunk size, so that a
+    // partial cell at the seam cannot hide neighbors one cell further away
+    pub(crate) fn tiling(length: f32, chunk_size: f32, periodic: bool) -> (usize, f32) {
+        if periodic {
+            let count: usize = ((length / chunk_size).floor() as usize).max(1);
+            (count, length.max(1.0) / count as f32)
+        } else {
+            (((length / chunk_size).ceil() as usize).max(1), chunk_size)
         }
+    }
 
-        neighbors
+    fn collect_around(
+        &self,
+        pos: &Vec2,
+        (reach_x, reach_y): (usize, usize),
+        inner: Option<usize>,
+    ) -> impl Iterator<Item = EntityId> + '_ {
+        let (chunk_x, chunk_y): (usize, usize) = if self.cells.is_empty() {
+            (0, 0)
+        } else {
+            self.chunk_coords(pos)
+        };
+        let (start_x, count_x): (isize, usize) =
+            Self::span(chunk_x, reach_x, self.columns, self.periodic);
+        let (start_y, count_y): (isize, usize) =
+            Self::span(chunk_y, reach_y, self.rows, self.periodic);
+        let (columns, rows): (isize, isize) = (self.columns as isize, self.rows as isize);
+        let inner: Option<((isize, usize), (isize, usize))> = inner.map(|reach| {
+            (
+                Self::span(chunk_x, reach, self.columns, self.periodic),
+                Self::span(chunk_y, reach, self.rows, self.periodic),
+            )
+        });
+
+        (0..count_y)
+            .flat_map(move |dy| {
+                let y: usize = (start_y + dy as isize).rem_euclid(rows) as usize;
+                (0..count_x).map(move |dx| {
+                    let x: usize = (start_x + dx as isize).rem_euclid(columns) as usize;
+                    (x, y)
+                })
+            })
+            .filter(move |&(x, y)| {
+                inner.is_none_or(|((inner_x, inner_count_x), (inner_y, inner_count_y))| {
+                    (x as isize - inner_x).rem_euclid(columns) as usize >= inner_count_x
+                        || (y as isize - inner_y).rem_euclid(rows) as usize >= inner_count_y
+                })
+            })
+            .flat_map(move |(x, y)| self.cells[y * self.columns + x].iter().copied())
     }
 
     #[inline]
-    fn chunk_id_from_pos(&self, pos: &Vec2) -> u32 {
-        let (x, y): (u32, u32) = self.chunk_coords(pos);
-        Self::chunk_id_from_coords(x, y)
+    fn span(center: usize, reach: usize, len: usize, periodic: bool) -> (isize, usize) {
+        if periodic {
+            if 2 * reach + 1 >= len {
+                (0, len)
+            } else {
+                (center as isize - reach as isize, 2 * reach + 1)
+            }
+        } else {
+            let start: usize = center.saturating_sub(reach);
+            let end: usize = (center + reach).min(len.saturating_sub(1));
+            (start as isize, (end + 1).saturating_sub(start))
+        }
     }
 
-    #[inline]
-    fn chunk_id_from_coords(x: u32, y: u32) -> u32 {
-        let sum: u32 = x + y;
-        (sum * (sum + 1)) / 2 + y
+    fn remove_slot(&mut self, idx: usize) {
+        let Slot { cell, offset, .. } = self.slots[idx];
+
+        self.cells[cell].swap_remove(offset);
+        if let Some(&moved) = self.cells[cell].get(offset) {
+            self.slots[moved.index() as usize].offset = offset;
+        }
+
+        self.slots[idx] = Slot::default();
+        self.len -= 1;
     }
 
     #[inline]
-    fn chunk_coords(&self, pos: &Vec2) -> (u32, u32) {
-        debug_assert!(pos.x >= 0.0, "Position {pos} is negative in x");
-        debug_assert!(pos.y >= 0.0, "Position {pos} is negative in y");
+    fn cell_from_pos(&self, pos: &Vec2) -> usize {
+        let (x, y): (usize, usize) = self.chunk_coords(pos);
+        y * self.columns + x
+    }
 
+    #[inline]
+    fn chunk_coords(&self, pos: &Vec2) -> (usize, usize) {
         (
-            (pos.x / self.chunk_size).floor() as u32,
-            (pos.y / self.chunk_size).floor() as u32,
+            ((pos.x / self.cell_width).floor().max(0.0) as usize).min(self.columns - 1),
+            ((pos.y / self.cell_height).floor().max(0.0) as usize).min(self.rows - 1),
         )
     }
 }
@@ -125,7 +286,7 @@ impl Chunks {
 #[cfg(test)]
 mod tests {
     use super::Chunks;
-    use crate::Vec2;
+    use crate::{BoundaryMode, Config, Vec2};
     use shipyard::EntityId;
     use std::collections::HashSet;
 
@@ -133,114 +294,321 @@ mod tests {
         EntityId::new_from_index_and_gen(index, 0)
     }
 
+    fn mock_config(width: usize, height: usize, chunk_size: f32) -> Config {
+        Config {
+            width,
+            height,
+            attraction_radius: chunk_size,
+            alignment_radius: chunk_size / 2.0,
+            avoidance_radius: chunk_size / 4.0,
+            ..Config::default()
+        }
+    }
+
+    fn chunks(width: usize, height: usize, chunk_size: f32) -> Chunks {
+        Chunks::new(&mock_config(width, height, chunk_size))
+    }
+
     #[test]
     fn chunk_new() {
-        let chunk_size: f32 = 32.0;
-        let chunks_repository: Chunks = Chunks::new(chunk_size);
+        let chunks_repository: Chunks = chunks(100, 45, 10.0);
+
+        assert_eq!(chunks_repository.chunk_size(), 10.0);
+        assert_eq!(chunks_repository.columns, 10);
+        assert_eq!(chunks_repository.rows, 4);
+        assert_eq!(chunks_repository.cell_height, 11.25);
+        assert!(chunks_repository.is_empty());
+
+        let bounded: Chunks = Chunks::new(&Config {
+            boundary_mode: BoundaryMode::Reflect,
+            ..mock_config(100, 45, 10.0)
+        });
+        assert_eq!(bounded.rows, 5);
+        assert_eq!(bounded.cell_height, 10.0);
+    }
 
-        assert_eq!(chunks_repository.chunk_size, chunk_size);
-        assert!(chunks_repository.chunks.is_empty());
+    #[test]
+    fn chunk_size_follows_largest_radius() {
+        let cfg: Config = Config {
+            attraction_radius: 40.0,
+            alignment_radius: 60.0,
+            ..Config::default()
+        };
+
+        assert_eq!(Chunks::chunk_size_for(&cfg), 60.0);
     }
 
     #[test]
     fn chunk_clear() {
-        let chunk_size: f32 = 10.0;
-        let mut chunks_repository: Chunks = Chunks::new(chunk_size);
-        let entity_position: Vec2 = Vec2::new(5.0, 5.0);
-        let entity_identifier: EntityId = mock_id(1);
+        let mut chunks_repository: Chunks = chunks(100, 100, 10.0);
 
-        chunks_repository.store(&entity_position, entity_identifier);
-        assert!(!chunks_repository.chunks.is_empty());
+        chunks_repository.update(mock_id(1), &Vec2::new(5.0, 5.0));
+        assert_eq!(chunks_repository.len(), 1);
 
         chunks_repository.clear();
-        assert!(chunks_repository.chunks.is_empty());
+        assert!(chunks_repository.is_empty());
+        assert!(chunks_repository
+            .load_chunk(&Vec2::new(5.0, 5.0))
+            .is_empty());
     }
 
     #[test]
-    fn chunk_resize() {
-        let initial_chunk_size: f32 = 32.0;
-        let target_chunk_size: f32 = 64.0;
-        let mut chunks_repository: Chunks = Chunks::new(initial_chunk_size);
+    fn chunk_configure() {
+        let mut chunks_repository: Chunks = chunks(100, 100, 10.0);
+        chunks_repository.update(mock_id(1), &Vec2::new(5.0, 5.0));
 
-        chunks_repository.resize(target_chunk_size);
+        assert!(!chunks_repository.configure(&mock_config(100, 100, 10.0)));
+        assert_eq!(chunks_repository.len(), 1);
 
-        assert_eq!(chunks_repository.chunk_size, target_chunk_size);
+        assert!(chunks_repository.configure(&mock_config(100, 100, 20.0)));
+        assert_eq!(chunks_repository.chunk_size(), 20.0);
+        assert!(chunks_repository.is_empty());
     }
 
     #[test]
-    fn chunk_store() {
-        let chunk_size: f32 = 10.0;
-        let mut chunks_repository: Chunks = Chunks::new(chunk_size);
-        let entity_position: Vec2 = Vec2::new(15.0, 15.0);
-        let entity_identifier: EntityId = mock_id(42);
-
-        let expected_chunk_identifier: u32 = 4;
-
-        chunks_repository.store(&entity_position, entity_identifier);
+    fn chunk_update_moves_entity() {
+        let mut chunks_repository: Chunks = chunks(100, 100, 10.0);
+        let id: EntityId = mock_id(42);
+
+        chunks_repository.update(id, &Vec2::new(15.0, 15.0));
+        assert_eq!(chunks_repository.load_chunk(&Vec2::new(12.0, 18.0)), &[id]);
+
+        chunks_repository.update(id, &Vec2::new(16.0, 16.0));
+        chunks_repository.update(id, &Vec2::new(55.0, 15.0));
+        assert!(chunks_repository
+            .load_chunk(&Vec2::new(15.0, 15.0))
+            .is_empty());
+        assert_eq!(chunks_repository.load_chunk(&Vec2::new(55.0, 15.0)), &[id]);
+        assert_eq!(chunks_repository.len(), 1);
+    }
 
-        let stored_chunk: &HashSet<EntityId> = chunks_repository
-            .chunks
-            .get(&expected_chunk_identifier)
-            .expect("Chunk should exist after storage");
+    #[test]
+    fn chunk_remove() {
+        let mut chunks_repository: Chunks = chunks(100, 100, 10.0);
+        let position: Vec2 = Vec2::new(5.0, 5.0);
 
-        assert!(stored_chunk.contains(&entity_identifier));
+        for index in 0..3 {
+            chunks_repository.update(mock_id(index), &position);
+        }
+        chunks_repository.remove(mock_id(0));
+        chunks_repository.remove(mock_id(7));
+
+        let remaining: HashSet<EntityId> = chunks_repository
+            .load_chunk(&position)
+            .iter()
+            .copied()
+            .collect();
+        assert_eq!(remaining, HashSet::from([mock_id(1), mock_id(2)]));
+
+        chunks_repository.remove(mock_id(2));
+        chunks_repository.update(mock_id(1), &Vec2::new(50.0, 50.0));
+        assert!(chunks_repository.load_chunk(&position).is_empty());
     }
 
     #[test]
-    fn chunk_remove() {
-        let chunk_size: f32 = 10.0;
-        let mut chunks_repository: Chunks = Chunks::new(chunk_size);
-        let entity_position: Vec2 = Vec2::new(5.0, 5.0);
-        let entity_identifier: EntityId = mock_id(100);
+    fn chunk_sweep_removes_stale_entities() {
+        let mut chunks_repository: Chunks = chunks(100, 100, 10.0);
 
-        chunks_repository.store(&entity_position, entity_identifier);
-        chunks_repository.remove(&entity_position, entity_identifier);
+        chunks_repository.begin_update();
+        for index in 0..4 {
+            chunks_repository.update(mock_id(index), &Vec2::new(5.0, 5.0));
+        }
+        chunks_repository.sweep();
+        assert_eq!(chunks_repository.len(), 4);
+
+        chunks_repository.begin_update();
+        chunks_repository.update(mock_id(1), &Vec2::new(5.0, 5.0));
+        chunks_repository.update(mock_id(3), &Vec2::new(95.0, 95.0));
+        chunks_repository.sweep();
+
+        assert_eq!(chunks_repository.len(), 2);
+        assert_eq!(
+            chunks_repository.load_chunk(&Vec2::new(5.0, 5.0)),
+            &[mock_id(1)]
+        );
+    }
 
-        assert!(chunks_repository.chunks.is_empty());
+    #[test]
+    fn chunk_reused_index_replaces_entity() {
+        let mut chunks_repository: Chunks = chunks(100, 100, 10.0);
+        let reused: EntityId = EntityId::new_from_index_and_gen(3, 1);
+
+        chunks_repository.update(mock_id(3), &Vec2::new(5.0, 5.0));
+        chunks_repository.update(reused, &Vec2::new(5.0, 5.0));
+
+        assert_eq!(chunks_repository.len(), 1);
+        assert_eq!(
+            chunks_repository.load_chunk(&Vec2::new(5.0, 5.0)),
+            &[reused]
+        );
     }
 
     #[test]
     fn chunk_load_chunk() {
-        // TODO
+        let mut chunks_repository: Chunks = chunks(100, 100, 10.0);
+
+        chunks_repository.update(mock_id(1), &Vec2::new(21.0, 31.0));
+        chunks_repository.update(mock_id(2), &Vec2::new(29.9, 39.9));
+        chunks_repository.update(mock_id(3), &Vec2::new(30.0, 31.0));
+
+        let chunk: HashSet<EntityId> = chunks_repository
+            .load_chunk(&Vec2::new(25.0, 35.0))
+            .iter()
+            .copied()
+            .collect();
+
+        assert_eq!(chunk, HashSet::from([mock_id(1), mock_id(2)]));
+        assert!(chunks_repository
+            .load_chunk(&Vec2::new(85.0, 5.0))
+            .is_empty());
     }
 
     #[test]
     fn chunk_load_neighbors() {
-        // TODO
+        let mut chunks_repository: Chunks = chunks(100, 100, 10.0);
+
+        chunks_repository.update(mock_id(1), &Vec2::new(95.0, 5.0));
+        chunks_repository.update(mock_id(2), &Vec2::new(15.0, 95.0));
+        chunks_repository.update(mock_id(3), &Vec2::new(55.0, 55.0));
+        chunks_repository.update(mock_id(4), &Vec2::new(5.0, 5.0));
+        chunks_repository.update(mock_id(5), &Vec2::new(95.0, 95.0));
+
+        let neighbors: Vec<EntityId> = chunks_repository
+            .load_neighbors(&Vec2::new(5.0, 5.0))
+            .collect();
+
+        assert_eq!(neighbors.len(), 3);
+        assert_eq!(
+            neighbors.into_iter().collect::<HashSet<EntityId>>(),
+            HashSet::from([mock_id(1), mock_id(2), mock_id(5)])
+        );
     }
 
     #[test]
-    fn chunk_id_from_pos() {
-        let chunk_size: f32 = 10.0;
-        let chunks_repository: Chunks = Chunks::new(chunk_size);
-        let entity_position: Vec2 = Vec2::new(12.0, 12.0);
+    fn chunk_load_neighbors_without_wrap() {
+        for boundary_mode in [BoundaryMode::Reflect, BoundaryMode::SoftWall] {
+            let mut chunks_repository: Chunks = Chunks::new(&Config {
+                boundary_mode,
+                ..mock_config(100, 100, 10.0)
+            });
+
+            chunks_repository.update(mock_id(1), &Vec2::new(95.0, 5.0));
+            chunks_repository.update(mock_id(2), &Vec2::new(15.0, 15.0));
 
-        let expected_identifier: u32 = 4;
-        let actual_identifier: u32 = chunks_repository.chunk_id_from_pos(&entity_position);
+            let neighbors: Vec<EntityId> = chunks_repository
+                .load_neighbors(&Vec2::new(5.0, 5.0))
+                .collect();
 
-        assert_eq!(actual_identifier, expected_identifier);
+            assert_eq!(neighbors, vec![mock_id(2)]);
+        }
     }
 
     #[test]
-    fn chunk_id_from_coords() {
-        let coordinate_x: u32 = 1;
-        let coordinate_y: u32 = 1;
+    fn chunk_load_neighbors_partial_last_chunk() {
+        let mut chunks_repository: Chunks = chunks(100, 30, 30.0);
+
+        chunks_repository.update(mock_id(1), &Vec2::new(95.0, 5.0));
 
-        let expected_identifier: u32 = 4;
-        let actual_identifier: u32 = Chunks::chunk_id_from_coords(coordinate_x, coordinate_y);
+        let neighbors: Vec<EntityId> = chunks_repository
+            .load_neighbors(&Vec2::new(5.0, 5.0))
+            .collect();
 
-        assert_eq!(actual_identifier, expected_identifier);
+        assert_eq!(neighbors, vec![mock_id(1)]);
     }
 
     #[test]
-    fn chunk_coords() {
-        let chunk_size: f32 = 16.0;
-        let chunks_repository: Chunks = Chunks::new(chunk_size);
-        let entity_position: Vec2 = Vec2::new(33.0, 15.0);
+    fn chunk_load_radius_across_partial_seam() {
+        let mut chunks_repository: Chunks = chunks(100, 100, 30.0);
+
+        chunks_repository.update(mock_id(1), &Vec2::new(85.0, 50.0));
+        chunks_repository.update(mock_id(2), &Vec2::new(50.0, 85.0));
+
+        let found: HashSet<EntityId> = chunks_repository
+            .load_radius(&Vec2::new(5.0, 5.0), 30.0)
+            .collect();
+        assert!(found.contains(&mock_id(2)));
+
+        let found: HashSet<EntityId> = chunks_repository
+            .load_radius(&Vec2::new(5.0, 50.0), 30.0)
+            .collect();
+        assert!(found.contains(&mock_id(1)));
+    }
+
+    #[test]
+    fn chunk_load_radius_visits_each_chunk_once() {
+        let mut chunks_repository: Chunks = chunks(40, 40, 10.0);
+
+        for index in 0..16 {
+            let position: Vec2 = Vec2::new(
+                (index % 4) as f32 * 10.0 + 5.0,
+                (index / 4) as f32 * 10.0 + 5.0,
+            );
+            chunks_repository.update(mock_id(index), &position);
+        }
+
+        let found: Vec<EntityId> = chunks_repository
+            .load_radius(&Vec2::new(5.0, 5.0), 1_000.0)
+            .collect();
+        assert_eq!(found.len(), 16);
+
+        let found: Vec<EntityId> = chunks_repository
+            .load_radius(&Vec2::new(5.0, 5.0), 10.0)
+            .collect();
+        assert_eq!(found.len(), 9);
+    }
+
+    #[test]
+    fn chunk_load_ring() {
+        let mut chunks_repository: Chunks = chunks(50, 50, 10.0);
+
+        for index in 0..25 {
+            let position: Vec2 = Vec2::new(
+                (index % 5) as f32 * 10.0 + 5.0,
+                (index / 5) as f32 * 10.0 + 5.0,
+            );
+            chunks_repository.update(mock_id(index), &position);
+        }
+
+        let center: Vec2 = Vec2::new(5.0, 5.0);
+        let rings: Vec<HashSet<EntityId>> = (0..4)
+            .map(|reach| chunks_repository.load_ring(&center, reach).collect())
+            .collect();
+        assert_eq!(rings[0], HashSet::from([mock_id(0)]));
+        assert_eq!(rings[1].len(), 8);
+        assert_eq!(rings[2].len(), 16);
+        assert!(rings[3].is_empty());
+        assert!(rings[1].contains(&mock_id(24)));
+    }
 
-        let expected_coordinates: (u32, u32) = (2, 0);
-        let actual_coordinates: (u32, u32) = chunks_repository.chunk_coords(&entity_position);
+    #[test]
+    fn chunk_covers() {
+        let periodic: Chunks = chunks(100, 50, 10.0);
+        assert!(!periodic.covers(4));
+        assert!(periodic.covers(5));
+        assert_eq!(periodic.clearance(3), 30.0);
+
+        let bounded: Chunks = Chunks::new(&Config {
+            boundary_mode: BoundaryMode::Reflect,
+            ..mock_config(100, 50, 10.0)
+        });
+        assert!(!bounded.covers(8));
+        assert!(bounded.covers(9));
+    }
 
-        assert_eq!(actual_coordinates, expected_coordinates);
+    #[test]
+    fn chunk_coords() {
+        let chunks_repository: Chunks = Chunks::new(&Config {
+            boundary_mode: BoundaryMode::Reflect,
+            ..mock_config(100, 100, 16.0)
+        });
+
+        assert_eq!(
+            chunks_repository.chunk_coords(&Vec2::new(33.0, 15.0)),
+            (2, 0)
+        );
+        assert_eq!(
+            chunks_repository.chunk_coords(&Vec2::new(-3.0, 100.0)),
+            (0, 6)
+        );
     }
 }
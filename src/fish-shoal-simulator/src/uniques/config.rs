@@ -14,32 +14,692 @@
  * limitations under the License.
  */
 
+use crate::{Angle, Boundary, Error, Vec2};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use shipyard::Unique;
+use std::{array, cmp::Ordering};
+
+pub const MAX_SPECIES: usize = 4;
+pub const MAX_GOALS: usize = 2;
+
+const SPECIES_COLORS: [[u8; 3]; MAX_SPECIES] = [
+    [80, 160, 255],
+    [255, 170, 60],
+    [120, 220, 120],
+    [220, 100, 220],
+];
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MouseMode {
+    #[default]
+    None,
+    Predator,
+    Attractor,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BoundaryMode {
+    #[default]
+    Wrap,
+    Reflect,
+    SoftWall,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HuntingStrategy {
+    Densest,
+    #[default]
+    MostIsolated,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BehaviorModelKind {
+    #[default]
+    Zonal,
+    Vicsek,
+    Couzin,
+    Reynolds,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InteractionMode {
+    #[default]
+    Metric,
+    Topological,
+    Voronoi,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SteeringMode {
+    Lerp,
+    #[default]
+    Force,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StressMode {
+    #[default]
+    Rules,
+    Contagion,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SpeciesInteraction {
+    #[default]
+    Align,
+    Attract,
+    Ignore,
+    Avoid,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GoalKind {
+    #[default]
+    Direction,
+    Point,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct BehaviorParams {
+    pub avoidance_speed: f32,
+    pub avoidance_stress: f32,
+    pub alignment_speed: f32,
+    pub alignment_stress: f32,
+    pub attraction_speed: f32,
+    pub attraction_stress: f32,
+    pub alone_speed: f32,
+    pub alone_stress: f32,
+
+    pub idle_speed_min: f32,
+    pub idle_speed_max: f32,
+    pub idle_stress_min: f32,
+    pub idle_stress_max: f32,
+
+    pub spawn_speed_min: f32,
+    pub spawn_speed_max: f32,
+    pub spawn_stress: f32,
+
+    pub lerp_rate: f32,
+    pub lerp_epsilon: f32,
+
+    pub mouse_flee_speed: f32,
+    pub mouse_panic_speed: f32,
+    pub mouse_feed_speed: f32,
+    pub mouse_feed_stress: f32,
+    pub mouse_feed_distance: f32,
+
+    pub escape_speed: f32,
+    pub escape_stress: f32,
+
+    pub hunger_rate: f32,
+    pub sated_hunger: f32,
+    pub wander_speed_factor: f32,
+    pub predator_stress: f32,
+}
+
+impl BehaviorParams {
+    fn validate(&self, problems: &mut Vec<String>) {
+        for (name, speed) in [
+            ("avoidance_speed", self.avoidance_speed),
+            ("alignment_speed", self.alignment_speed),
+            ("attraction_speed", self.attraction_speed),
+            ("alone_speed", self.alone_speed),
+            ("idle_speed_min", self.idle_speed_min),
+            ("spawn_speed_min", self.spawn_speed_min),
+            ("lerp_rate", self.lerp_rate),
+            ("lerp_epsilon", self.lerp_epsilon),
+            ("mouse_flee_speed", self.mouse_flee_speed),
+            ("mouse_feed_speed", self.mouse_feed_speed),
+            ("mouse_feed_distance", self.mouse_feed_distance),
+            ("escape_speed", self.escape_speed),
+            ("hunger_rate", self.hunger_rate),
+            ("wander_speed_factor", self.wander_speed_factor),
+        ] {
+            if !(speed.is_finite() && speed >= 0.0) {
+                problems.push(format!(
+                    "behavior.{name} must be a non-negative number, got {speed}"
+                ));
+            }
+        }
+
+        for (name, stress) in [
+            ("avoidance_stress", self.avoidance_stress),
+            ("alignment_stress", self.alignment_stress),
+            ("attraction_stress", self.attraction_stress),
+            ("alone_stress", self.alone_stress),
+            ("idle_stress_min", self.idle_stress_min),
+            ("idle_stress_max", self.idle_stress_max),
+            ("spawn_stress", self.spawn_stress),
+            ("mouse_feed_stress", self.mouse_feed_stress),
+            ("escape_stress", self.escape_stress),
+            ("sated_hunger", self.sated_hunger),
+            ("predator_stress", self.predator_stress),
+        ] {
+            if !(0.0..=1.0).contains(&stress) {
+                problems.push(format!(
+                    "behavior.{name} must be within 0..=1, got {stress}"
+                ));
+            }
+        }
+
+        if !(self.mouse_panic_speed.is_finite() && self.mouse_panic_speed > 0.0) {
+            problems.push(format!(
+                "behavior.mouse_panic_speed must be a positive number, got {}",
+                self.mouse_panic_speed
+            ));
+        }
+
+        for (name, min, max) in [
+            ("idle_speed", self.idle_speed_min, self.idle_speed_max),
+            ("idle_stress", self.idle_stress_min, self.idle_stress_max),
+            ("spawn_speed", self.spawn_speed_min, self.spawn_speed_max),
+        ] {
+            if min.partial_cmp(&max) != Some(Ordering::Less) {
+                problems.push(format!(
+                    "behavior.{name}_min ({min}) must be less than behavior.{name}_max ({max})"
+                ));
+            }
+        }
+    }
+}
+
+impl Default for BehaviorParams {
+    fn default() -> Self {
+        Self {
+            avoidance_speed: 100.0,
+            avoidance_stress: 0.95,
+            alignment_speed: 75.0,
+            alignment_stress: 0.33,
+            attraction_speed: 100.0,
+            attraction_stress: 0.5,
+            alone_speed: 50.0,
+            alone_stress: 0.1,
+
+            idle_speed_min: 10.0,
+            idle_speed_max: 100.0,
+            idle_stress_min: 0.1,
+            idle_stress_max: 0.5,
+
+            spawn_speed_min: 50.0,
+            spawn_speed_max: 100.0,
+            spawn_stress: 0.1,
+
+            lerp_rate: 5.0,
+            lerp_epsilon: 0.1,
+
+            mouse_flee_speed: 100.0,
+            mouse_panic_speed: 1_000.0,
+            mouse_feed_speed: 75.0,
+            mouse_feed_stress: 0.33,
+            mouse_feed_distance: 5.0,
+
+            escape_speed: 150.0,
+            escape_stress: 1.0,
+
+            hunger_rate: 0.1,
+            sated_hunger: 0.25,
+            wander_speed_factor: 0.5,
+            predator_stress: 0.5,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct StressParams {
+    pub baseline: f32,
+    pub decay_rate: f32,
+    pub alarm_threshold: f32,
+    pub alarm_speed: f32,
+    pub alarm_stress: f32,
+    pub transmission_probability: f64,
+    pub transmission_delay: f32,
+    pub transmission_radius: f32,
+    pub refractory_period: f32,
+}
+
+impl StressParams {
+    fn validate(&self, problems: &mut Vec<String>) {
+        for (name, value) in [
+            ("decay_rate", self.decay_rate),
+            ("alarm_speed", self.alarm_speed),
+            ("transmission_delay", self.transmission_delay),
+            ("transmission_radius", self.transmission_radius),
+            ("refractory_period", self.refractory_period),
+        ] {
+            if !(value.is_finite() && value >= 0.0) {
+                problems.push(format!(
+                    "stress.{name} must be a non-negative number, got {value}"
+                ));
+            }
+        }
+
+        for (name, value) in [
+            ("baseline", self.baseline),
+            ("alarm_threshold", self.alarm_threshold),
+            ("alarm_stress", self.alarm_stress),
+            (
+                "transmission_probability",
+                self.transmission_probability as f32,
+            ),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                problems.push(format!("stress.{name} must be within 0..=1, got {value}"));
+            }
+        }
+
+        if self.baseline >= self.alarm_threshold {
+            problems.push(format!(
+                "stress.baseline ({}) must be less than stress.alarm_threshold ({})",
+                self.baseline, self.alarm_threshold
+            ));
+        }
+    }
+}
+
+impl Default for StressParams {
+    fn default() -> Self {
+        Self {
+            baseline: 0.1,
+            decay_rate: 1.0,
+            alarm_threshold: 0.6,
+            alarm_speed: 150.0,
+            alarm_stress: 1.0,
+            transmission_probability: 0.3,
+            transmission_delay: 0.15,
+            transmission_radius: 30.0,
+            refractory_period: 3.0,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct TraitDistribution {
+    pub mean: f32,
+    pub std_dev: f32,
+}
+
+impl TraitDistribution {
+    pub const fn fixed(mean: f32) -> Self {
+        Self { mean, std_dev: 0.0 }
+    }
+}
+
+impl Default for TraitDistribution {
+    fn default() -> Self {
+        Self::fixed(1.0)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct TraitParams {
+    pub body_length: TraitDistribution,
+    pub speed: TraitDistribution,
+    pub perception: TraitDistribution,
+    pub sociability: TraitDistribution,
+    pub boldness: TraitDistribution,
+}
+
+impl TraitParams {
+    fn validate(&self, prefix: &str, problems: &mut Vec<String>) {
+        for (name, distribution) in [
+            ("body_length", self.body_length),
+            ("speed", self.speed),
+            ("perception", self.perception),
+            ("sociability", self.sociability),
+            ("boldness", self.boldness),
+        ] {
+            if !(distribution.mean.is_finite() && distribution.mean > 0.0) {
+                problems.push(format!(
+                    "{prefix}.{name}.mean must be a positive number, got {}",
+                    distribution.mean
+                ));
+            }
+            if !(distribution.std_dev.is_finite() && distribution.std_dev >= 0.0) {
+                problems.push(format!(
+                    "{prefix}.{name}.std_dev must be a non-negative number, got {}",
+                    distribution.std_dev
+                ));
+            }
+        }
+
+        for (name, distribution) in [
+            ("sociability", self.sociability),
+            ("boldness", self.boldness),
+        ] {
+            if distribution.mean > 1.0 {
+                problems.push(format!(
+                    "{prefix}.{name}.mean must be within 0..=1, got {}",
+                    distribution.mean
+                ));
+            }
+        }
+    }
+}
+
+impl Default for TraitParams {
+    fn default() -> Self {
+        Self {
+            body_length: TraitDistribution::fixed(10.0),
+            speed: TraitDistribution::fixed(1.0),
+            perception: TraitDistribution::fixed(1.0),
+            sociability: TraitDistribution::fixed(1.0),
+            boldness: TraitDistribution::fixed(0.5),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct SpeciesParams {
+    pub count: usize,
+    pub color: [u8; 3],
+    pub traits: TraitParams,
+    pub interactions: [SpeciesInteraction; MAX_SPECIES],
+}
+
+impl SpeciesParams {
+    pub fn new(count: usize, color: [u8; 3]) -> Self {
+        Self {
+            count,
+            color,
+            traits: TraitParams::default(),
+            interactions: [SpeciesInteraction::Align; MAX_SPECIES],
+        }
+    }
+}
+
+impl Default for SpeciesParams {
+    fn default() -> Self {
+        Self::new(0, SPECIES_COLORS[0])
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct GoalParams {
+    pub kind: GoalKind,
+    pub direction: f32,
+    pub point: [f32; 2],
+    pub share: f32,
+}
+
+impl GoalParams {
+    pub fn heading(&self, position: Vec2, boundary: Boundary) -> Vec2 {
+        match self.kind {
+            GoalKind::Direction => Angle::from_degrees(self.direction).to_vector(),
+            GoalKind::Point => boundary.offset(position, self.point.into()).normalized(),
+        }
+    }
+
+    fn validate(&self, index: usize, problems: &mut Vec<String>) {
+        if !(self.share.is_finite() && self.share >= 0.0) {
+            problems.push(format!(
+                "goals[{index}].share must be a non-negative number, got {}",
+                self.share
+            ));
+        }
+        if !(self.direction.is_finite() && self.point.iter().all(|value| value.is_finite())) {
+            problems.push(format!(
+                "goals[{index}] direction and point must be finite numbers"
+            ));
+        }
+    }
+}
+
+impl Default for GoalParams {
+    fn default() -> Self {
+        Self {
+            kind: GoalKind::Direction,
+            direction: 0.0,
+            point: [0.0, 0.0],
+            share: 1.0,
+        }
+    }
+}
 
 #[derive(Unique, Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(default, deny_unknown_fields)
+)]
 pub struct Config {
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub mouse_pos: Option<[f32; 2]>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub mouse_vel: Option<[f32; 2]>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub mouse_speed: Option<f32>,
+    pub mouse_mode: MouseMode,
+    pub mouse_radius: f32,
 
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub running: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub paused: bool,
 
     pub width: usize,
     pub height: usize,
+    pub boundary_mode: BoundaryMode,
+    pub boundary_margin: f32,
 
-    pub entity_count: usize,
+    pub predator_count: usize,
+    pub predator_strategy: HuntingStrategy,
+    pub predator_speed: f32,
+    pub predator_vision_radius: f32,
+    pub predator_capture_radius: f32,
+    pub predator_attack_cooldown: f32,
+    pub prey_detection_radius: f32,
+    pub obstacle_look_ahead: f32,
 
     pub direction_change_prob: f64,
     pub speed_change_prob: f64,
     pub stress_change_prob: f64,
 
+    pub steering_mode: SteeringMode,
+    pub max_turn_rate: f32,
+    pub max_acceleration: f32,
+    pub max_deceleration: f32,
+    pub drag: f32,
+    pub cruise_speed: f32,
+
+    pub behavior_model: BehaviorModelKind,
+    pub interaction_mode: InteractionMode,
+    pub topological_neighbors: usize,
+    pub vicsek_noise: f32,
+    pub couzin_blind_angle: f32,
+    pub reynolds_separation_weight: f32,
+    pub reynolds_alignment_weight: f32,
+    pub reynolds_cohesion_weight: f32,
+
     pub attraction_fov: f32,
     pub alignment_fov: f32,
 
     pub attraction_radius: f32,
     pub alignment_radius: f32,
     pub avoidance_radius: f32,
+
+    pub stress_mode: StressMode,
+    pub stress: StressParams,
+
+    pub behavior: BehaviorParams,
+    pub species: [SpeciesParams; MAX_SPECIES],
+
+    pub informed_fraction: f32,
+    pub goal_weight: f32,
+    pub goals: [GoalParams; MAX_GOALS],
+}
+
+impl Config {
+    pub fn fish_count(&self) -> usize {
+        self.species.iter().map(|species| species.count).sum()
+    }
+
+    #[inline]
+    pub fn interaction(&self, species: usize, other: usize) -> SpeciesInteraction {
+        self.species[species].interactions[other]
+    }
+
+    pub fn goal(&self, rank: f32) -> Option<usize> {
+        if rank >= self.informed_fraction {
+            return None;
+        }
+
+        let total: f32 = self.goals.iter().map(|goal| goal.share).sum();
+        let mut threshold: f32 = rank / self.informed_fraction * total;
+        for (index, goal) in self.goals.iter().enumerate() {
+            if threshold < goal.share {
+                return Some(index);
+            }
+            threshold -= goal.share;
+        }
+
+        self.goals.iter().rposition(|goal| goal.share > 0.0)
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut problems: Vec<String> = Vec::new();
+
+        if self.width == 0 || self.height == 0 {
+            problems.push(format!(
+                "world size must be non-zero, got {}x{}",
+                self.width, self.height
+            ));
+        }
+
+        for (name, prob) in [
+            ("direction_change_prob", self.direction_change_prob),
+            ("speed_change_prob", self.speed_change_prob),
+            ("stress_change_prob", self.stress_change_prob),
+        ] {
+            if !(0.0..=1.0).contains(&prob) {
+                problems.push(format!("{name} must be within 0..=1, got {prob}"));
+            }
+        }
+
+        if !(self.avoidance_radius > 0.0
+            && self.avoidance_radius < self.alignment_radius
+            && self.alignment_radius < self.attraction_radius)
+        {
+            problems.push(format!(
+                "radii must satisfy 0 < avoidance_radius ({}) < alignment_radius ({}) < attraction_radius ({})",
+                self.avoidance_radius, self.alignment_radius, self.attraction_radius
+            ));
+        }
+
+        for (name, fov) in [
+            ("attraction_fov", self.attraction_fov),
+            ("alignment_fov", self.alignment_fov),
+            ("couzin_blind_angle", self.couzin_blind_angle),
+        ] {
+            if !(0.0..=360.0).contains(&fov) {
+                problems.push(format!("{name} must be within 0..=360 degrees, got {fov}"));
+            }
+        }
+
+        for (name, value) in [
+            ("mouse_radius", self.mouse_radius),
+            ("boundary_margin", self.boundary_margin),
+            ("predator_speed", self.predator_speed),
+            ("predator_vision_radius", self.predator_vision_radius),
+            ("predator_capture_radius", self.predator_capture_radius),
+            ("predator_attack_cooldown", self.predator_attack_cooldown),
+            ("prey_detection_radius", self.prey_detection_radius),
+            ("obstacle_look_ahead", self.obstacle_look_ahead),
+            (
+                "reynolds_separation_weight",
+                self.reynolds_separation_weight,
+            ),
+            ("reynolds_alignment_weight", self.reynolds_alignment_weight),
+            ("reynolds_cohesion_weight", self.reynolds_cohesion_weight),
+            ("goal_weight", self.goal_weight),
+            ("max_turn_rate", self.max_turn_rate),
+            ("max_acceleration", self.max_acceleration),
+            ("max_deceleration", self.max_deceleration),
+            ("drag", self.drag),
+            ("cruise_speed", self.cruise_speed),
+        ] {
+            if !(value.is_finite() && value >= 0.0) {
+                problems.push(format!("{name} must be a non-negative number, got {value}"));
+            }
+        }
+
+        self.stress.validate(&mut problems);
+        self.behavior.validate(&mut problems);
+        for (index, species) in self.species.iter().enumerate() {
+            species
+                .traits
+                .validate(&format!("species[{index}].traits"), &mut problems);
+        }
+
+        for (index, goal) in self.goals.iter().enumerate() {
+            goal.validate(index, &mut problems);
+        }
+
+        if !(0.0..=1.0).contains(&self.informed_fraction) {
+            problems.push(format!(
+                "informed_fraction must be within 0..=1, got {}",
+                self.informed_fraction
+            ));
+        } else if self.informed_fraction > 0.0 && self.goals.iter().all(|goal| goal.share <= 0.0) {
+            problems.push("at least one goal needs a positive share".to_string());
+        }
+
+        if self.topological_neighbors == 0 {
+            problems.push("topological_neighbors must be at least 1".to_string());
+        }
+
+        if !(0.0..=1.0).contains(&self.vicsek_noise) {
+            problems.push(format!(
+                "vicsek_noise must be within 0..=1, got {}",
+                self.vicsek_noise
+            ));
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Config(problems.join("; ")))
+        }
+    }
 }
 
 impl Default for Config {
@@ -48,25 +708,304 @@ impl Default for Config {
             mouse_pos: None,
             mouse_vel: None,
             mouse_speed: None,
+            mouse_mode: MouseMode::None,
+            mouse_radius: 100.0,
 
             running: true,
             paused: false,
 
             width: 1_920,
             height: 1_080,
+            boundary_mode: BoundaryMode::Wrap,
+            boundary_margin: 50.0,
 
-            entity_count: 500,
+            predator_count: 0,
+            predator_strategy: HuntingStrategy::MostIsolated,
+            predator_speed: 120.0,
+            predator_vision_radius: 200.0,
+            predator_capture_radius: 5.0,
+            predator_attack_cooldown: 3.0,
+            prey_detection_radius: 80.0,
+            obstacle_look_ahead: 40.0,
 
             direction_change_prob: 0.1,
             speed_change_prob: 0.05,
             stress_change_prob: 0.001,
 
+            steering_mode: SteeringMode::Force,
+            max_turn_rate: 270.0,
+            max_acceleration: 150.0,
+            max_deceleration: 200.0,
+            drag: 0.5,
+            cruise_speed: 50.0,
+
+            behavior_model: BehaviorModelKind::Zonal,
+            interaction_mode: InteractionMode::Metric,
+            topological_neighbors: 6,
+            vicsek_noise: 0.1,
+            couzin_blind_angle: 60.0,
+            reynolds_separation_weight: 1.5,
+            reynolds_alignment_weight: 1.0,
+            reynolds_cohesion_weight: 1.0,
+
             attraction_fov: 100.0,
             alignment_fov: 250.0,
 
             attraction_radius: 50.0,
             alignment_radius: 30.0,
             avoidance_radius: 10.0,
+
+            stress_mode: StressMode::Rules,
+            stress: StressParams::default(),
+
+            behavior: BehaviorParams::default(),
+            species: array::from_fn(|index| {
+                SpeciesParams::new(if index == 0 { 500 } else { 0 }, SPECIES_COLORS[index])
+            }),
+
+            informed_fraction: 0.0,
+            goal_weight: 0.5,
+            goals: [
+                GoalParams {
+                    direction: 0.0,
+                    point: [1_440.0, 540.0],
+                    ..GoalParams::default()
+                },
+                GoalParams {
+                    direction: 180.0,
+                    point: [480.0, 540.0],
+                    share: 0.0,
+                    ..GoalParams::default()
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BehaviorParams, Config, GoalKind, SpeciesParams, TraitDistribution, TraitParams};
+    use crate::{Boundary, BoundaryMode, Error, Vec2};
+
+    fn problems(cfg: Config) -> String {
+        match cfg.validate() {
+            Err(Error::Config(msg)) => msg,
+            other => panic!("expected a config error, got {other:?}"),
         }
     }
+
+    #[test]
+    fn config_default_is_valid() {
+        assert!(Config::default().validate().is_ok());
+    }
+
+    #[test]
+    fn config_zero_sized_world() {
+        let cfg: Config = Config {
+            width: 0,
+            ..Config::default()
+        };
+        assert_eq!(problems(cfg), "world size must be non-zero, got 0x1080");
+    }
+
+    #[test]
+    fn config_probability_out_of_range() {
+        let cfg: Config = Config {
+            speed_change_prob: 1.5,
+            stress_change_prob: -0.1,
+            ..Config::default()
+        };
+        assert_eq!(
+            problems(cfg),
+            "speed_change_prob must be within 0..=1, got 1.5; \
+             stress_change_prob must be within 0..=1, got -0.1"
+        );
+    }
+
+    #[test]
+    fn config_inverted_radii() {
+        let cfg: Config = Config {
+            alignment_radius: 60.0,
+            ..Config::default()
+        };
+        assert!(problems(cfg).starts_with("radii must satisfy"));
+
+        let cfg: Config = Config {
+            avoidance_radius: 0.0,
+            ..Config::default()
+        };
+        assert!(problems(cfg).starts_with("radii must satisfy"));
+    }
+
+    #[test]
+    fn config_behavior_model_parameters() {
+        let cfg: Config = Config {
+            topological_neighbors: 0,
+            vicsek_noise: 2.0,
+            couzin_blind_angle: 400.0,
+            reynolds_cohesion_weight: -1.0,
+            ..Config::default()
+        };
+        assert_eq!(
+            problems(cfg),
+            "couzin_blind_angle must be within 0..=360 degrees, got 400; \
+             reynolds_cohesion_weight must be a non-negative number, got -1; \
+             topological_neighbors must be at least 1; \
+             vicsek_noise must be within 0..=1, got 2"
+        );
+    }
+
+    #[test]
+    fn config_behavior_params() {
+        let cfg: Config = Config {
+            behavior: BehaviorParams {
+                alignment_stress: 1.5,
+                idle_speed_min: 120.0,
+                escape_speed: -1.0,
+                predator_stress: 2.0,
+                mouse_panic_speed: 0.0,
+                ..BehaviorParams::default()
+            },
+            ..Config::default()
+        };
+        assert_eq!(
+            problems(cfg),
+            "behavior.escape_speed must be a non-negative number, got -1; \
+             behavior.alignment_stress must be within 0..=1, got 1.5; \
+             behavior.predator_stress must be within 0..=1, got 2; \
+             behavior.mouse_panic_speed must be a positive number, got 0; \
+             behavior.idle_speed_min (120) must be less than behavior.idle_speed_max (100)"
+        );
+    }
+
+    #[test]
+    fn config_trait_distributions() {
+        let mut cfg: Config = Config::default();
+        cfg.species[1] = SpeciesParams {
+            traits: TraitParams {
+                speed: TraitDistribution {
+                    mean: 1.0,
+                    std_dev: -0.1,
+                },
+                boldness: TraitDistribution::fixed(1.5),
+                ..TraitParams::default()
+            },
+            ..cfg.species[1]
+        };
+        assert_eq!(
+            problems(cfg),
+            "species[1].traits.speed.std_dev must be a non-negative number, got -0.1; \
+             species[1].traits.boldness.mean must be within 0..=1, got 1.5"
+        );
+    }
+
+    #[test]
+    fn config_species_table() {
+        let mut cfg: Config = Config::default();
+        assert_eq!(cfg.fish_count(), 500);
+
+        cfg.species[2].count = 40;
+        assert_eq!(cfg.fish_count(), 540);
+        assert_ne!(cfg.species[0].color, cfg.species[2].color);
+    }
+
+    #[test]
+    fn config_goal_assignment() {
+        let mut cfg: Config = Config::default();
+        assert_eq!(cfg.goal(0.0), None);
+
+        cfg.informed_fraction = 0.2;
+        cfg.goals[0].share = 3.0;
+        cfg.goals[1].share = 1.0;
+        assert_eq!(cfg.goal(0.0), Some(0));
+        assert_eq!(cfg.goal(0.149), Some(0));
+        assert_eq!(cfg.goal(0.151), Some(1));
+        assert_eq!(cfg.goal(0.2), None);
+
+        cfg.goals[0].share = 0.0;
+        assert_eq!(cfg.goal(0.01), Some(1));
+    }
+
+    #[test]
+    fn config_goal_heading() {
+        let mut cfg: Config = Config::default();
+        let wrap: Boundary = Boundary::new(BoundaryMode::Wrap, 100.0, 100.0);
+
+        cfg.goals[0].direction = 90.0;
+        let heading: Vec2 = cfg.goals[0].heading(Vec2::new(50.0, 50.0), wrap);
+        assert!(heading.x.abs() < 1e-6 && (heading.y - 1.0).abs() < 1e-6);
+
+        cfg.goals[0].kind = GoalKind::Point;
+        cfg.goals[0].point = [90.0, 50.0];
+        let heading: Vec2 = cfg.goals[0].heading(Vec2::new(10.0, 50.0), wrap);
+        assert!((heading.x + 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn config_goals_validation() {
+        let mut cfg: Config = Config {
+            informed_fraction: 1.5,
+            ..Config::default()
+        };
+        assert_eq!(
+            problems(cfg),
+            "informed_fraction must be within 0..=1, got 1.5"
+        );
+
+        cfg.informed_fraction = 0.1;
+        cfg.goals[0].share = 0.0;
+        assert_eq!(problems(cfg), "at least one goal needs a positive share");
+
+        cfg.goals[1].share = -1.0;
+        assert!(problems(cfg).starts_with("goals[1].share must be a non-negative number"));
+    }
+
+    #[test]
+    fn config_stress_params() {
+        let mut cfg: Config = Config::default();
+        cfg.stress.transmission_probability = 1.5;
+        cfg.stress.alarm_stress = -0.5;
+        assert_eq!(
+            problems(cfg),
+            "stress.alarm_stress must be within 0..=1, got -0.5; \
+             stress.transmission_probability must be within 0..=1, got 1.5"
+        );
+
+        cfg.stress.alarm_stress = 1.0;
+
+        cfg.stress.transmission_probability = 0.5;
+        cfg.stress.baseline = 0.8;
+        assert_eq!(
+            problems(cfg),
+            "stress.baseline (0.8) must be less than stress.alarm_threshold (0.6)"
+        );
+    }
+
+    #[test]
+    fn config_negative_value() {
+        let cfg: Config = Config {
+            predator_speed: f32::NAN,
+            ..Config::default()
+        };
+        assert_eq!(
+            problems(cfg),
+            "predator_speed must be a non-negative number, got NaN"
+        );
+    }
+
+    #[test]
+    fn config_steering_params() {
+        let cfg: Config = Config {
+            max_turn_rate: -10.0,
+            max_deceleration: f32::NAN,
+            drag: f32::INFINITY,
+            ..Config::default()
+        };
+        assert_eq!(
+            problems(cfg),
+            "max_turn_rate must be a non-negative number, got -10; \
+             max_deceleration must be a non-negative number, got NaN; \
+             drag must be a non-negative number, got inf"
+        );
+    }
 }
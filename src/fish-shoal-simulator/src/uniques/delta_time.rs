@@ -14,23 +14,120 @@
  * limitations under the License.
  */
 
+use crate::Error;
 use shipyard::Unique;
 use std::{
     ops::Mul,
     time::{Duration, Instant},
 };
 
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ClockMode {
+    RealTime,
+    Fixed { step: f32, max_substeps: usize },
+    Scaled(f32),
+}
+
+impl ClockMode {
+    pub fn validate(&self) -> Result<(), Error> {
+        match *self {
+            Self::Fixed { step, .. } if !step.is_finite() => Err(Error::Config(format!(
+                "clock step must be a finite number, got {step}"
+            ))),
+            Self::Scaled(scale) if !scale.is_finite() => Err(Error::Config(format!(
+                "clock scale must be a finite number, got {scale}"
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
 #[derive(Unique, Debug, Copy, Clone)]
 pub struct DeltaTime {
     last_time: Instant,
     delta: Duration,
+    mode: ClockMode,
+    accumulator: Duration,
 }
 
 impl DeltaTime {
-    pub fn calc(&mut self) {
+    pub fn new(mode: ClockMode) -> Self {
+        Self {
+            mode,
+            ..Default::default()
+        }
+    }
+
+    pub fn calc(&mut self) -> usize {
         let now: Instant = Instant::now();
-        self.delta = now - self.last_time;
+        let elapsed: Duration = now - self.last_time;
         self.last_time = now;
+        self.accumulate(elapsed)
+    }
+
+    pub fn reset(&mut self) {
+        self.last_time = Instant::now();
+        self.accumulator = Duration::ZERO;
+    }
+
+    pub fn set(&mut self, dt: f32) {
+        self.delta = Self::duration(dt);
+    }
+
+    #[inline]
+    pub fn secs(&self) -> f32 {
+        self.delta.as_secs_f32()
+    }
+
+    #[inline]
+    pub fn mode(&self) -> ClockMode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: ClockMode) {
+        self.mode = mode;
+        self.accumulator = Duration::ZERO;
+    }
+
+    fn accumulate(&mut self, elapsed: Duration) -> usize {
+        match self.mode {
+            ClockMode::RealTime => {
+                self.delta = elapsed;
+                1
+            }
+            ClockMode::Scaled(scale) => {
+                self.delta = Self::duration(elapsed.as_secs_f32() * scale);
+                1
+            }
+            ClockMode::Fixed { step, max_substeps } => {
+                if !(step.is_finite() && step > 0.0) {
+                    self.delta = Duration::ZERO;
+                    return 0;
+                }
+
+                let step: Duration = Self::duration(step);
+                self.delta = step;
+                self.accumulator += elapsed;
+
+                let mut substeps: usize = 0;
+                while self.accumulator >= step && substeps < max_substeps {
+                    self.accumulator -= step;
+                    substeps += 1;
+                }
+
+                // Drop the backlog rather than spiralling when a frame is too long
+                if self.accumulator >= step {
+                    self.accumulator = Duration::ZERO;
+                }
+
+                substeps
+            }
+        }
+    }
+
+    // Negative and NaN durations count as zero, and overlong ones saturate instead of panicking
+    fn duration(secs: f32) -> Duration {
+        Duration::try_from_secs_f32(secs.max(0.0)).unwrap_or(Duration::MAX)
     }
 }
 
@@ -39,6 +136,8 @@ impl Default for DeltaTime {
         Self {
             last_time: Instant::now(),
             delta: Duration::default(),
+            mode: ClockMode::RealTime,
+            accumulator: Duration::ZERO,
         }
     }
 }
@@ -58,3 +157,94 @@ impl Mul<DeltaTime> for f32 {
         self * rhs.delta.as_secs_f32()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ClockMode, DeltaTime};
+    use std::time::Duration;
+
+    #[test]
+    fn delta_time_set() {
+        let mut delta_time: DeltaTime = DeltaTime::default();
+
+        delta_time.set(0.5);
+
+        assert_eq!(delta_time.secs(), 0.5);
+        assert_eq!(delta_time * 4.0, 2.0);
+        assert_eq!(4.0 * delta_time, 2.0);
+    }
+
+    #[test]
+    fn delta_time_real_time() {
+        let mut delta_time: DeltaTime = DeltaTime::new(ClockMode::RealTime);
+
+        let substeps: usize = delta_time.accumulate(Duration::from_millis(250));
+
+        assert_eq!(substeps, 1);
+        assert_eq!(delta_time.secs(), 0.25);
+    }
+
+    #[test]
+    fn delta_time_scaled() {
+        let mut delta_time: DeltaTime = DeltaTime::new(ClockMode::Scaled(2.0));
+
+        let substeps: usize = delta_time.accumulate(Duration::from_millis(250));
+
+        assert_eq!(substeps, 1);
+        assert_eq!(delta_time.secs(), 0.5);
+    }
+
+    #[test]
+    fn delta_time_fixed_accumulates() {
+        let mut delta_time: DeltaTime = DeltaTime::new(ClockMode::Fixed {
+            step: 0.01,
+            max_substeps: 8,
+        });
+
+        assert_eq!(delta_time.accumulate(Duration::from_millis(5)), 0);
+        assert_eq!(delta_time.accumulate(Duration::from_millis(5)), 1);
+        assert_eq!(delta_time.accumulate(Duration::from_millis(35)), 3);
+        assert!((delta_time.secs() - 0.01).abs() < 1e-6);
+    }
+
+    #[test]
+    fn delta_time_non_finite_inputs() {
+        let mut delta_time: DeltaTime = DeltaTime::default();
+
+        delta_time.set(f32::INFINITY);
+        assert_eq!(delta_time.delta, Duration::MAX);
+        delta_time.set(f32::NAN);
+        assert_eq!(delta_time.secs(), 0.0);
+
+        let mut scaled: DeltaTime = DeltaTime::new(ClockMode::Scaled(f32::INFINITY));
+        assert_eq!(scaled.accumulate(Duration::from_millis(10)), 1);
+        assert_eq!(scaled.delta, Duration::MAX);
+
+        let mut fixed: DeltaTime = DeltaTime::new(ClockMode::Fixed {
+            step: f32::NAN,
+            max_substeps: 4,
+        });
+        assert_eq!(fixed.accumulate(Duration::from_secs(1)), 0);
+        assert_eq!(fixed.secs(), 0.0);
+
+        assert!(ClockMode::Scaled(f32::NEG_INFINITY).validate().is_err());
+        assert!(ClockMode::Fixed {
+            step: f32::NAN,
+            max_substeps: 4
+        }
+        .validate()
+        .is_err());
+        assert!(ClockMode::Scaled(2.0).validate().is_ok());
+    }
+
+    #[test]
+    fn delta_time_fixed_max_substeps() {
+        let mut delta_time: DeltaTime = DeltaTime::new(ClockMode::Fixed {
+            step: 0.01,
+            max_substeps: 4,
+        });
+
+        assert_eq!(delta_time.accumulate(Duration::from_secs(1)), 4);
+        assert_eq!(delta_time.accumulate(Duration::ZERO), 0);
+    }
+}
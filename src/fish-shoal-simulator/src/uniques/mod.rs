@@ -14,10 +14,22 @@
  * limitations under the License.
  */
 
+mod alarm_wave;
+mod captures;
 mod chunks;
 mod config;
 mod delta_time;
+mod obstacles;
+mod sim_rng;
 
+pub use alarm_wave::AlarmWave;
+pub use captures::Captures;
 pub use chunks::Chunks;
-pub use config::Config;
-pub use delta_time::DeltaTime;
+pub use config::{
+    BehaviorModelKind, BehaviorParams, BoundaryMode, Config, GoalKind, GoalParams, HuntingStrategy,
+    InteractionMode, MouseMode, SpeciesInteraction, SpeciesParams, SteeringMode, StressMode,
+    StressParams, TraitDistribution, TraitParams, MAX_GOALS, MAX_SPECIES,
+};
+pub use delta_time::{ClockMode, DeltaTime};
+pub use obstacles::Obstacles;
+pub use sim_rng::{SimRng, StreamRng};
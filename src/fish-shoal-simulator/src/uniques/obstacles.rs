@@ -0,0 +1,146 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{Obstacle, Vec2};
+use shipyard::Unique;
+
+const MAX_PUSH_PASSES: usize = 8;
+
+#[derive(Unique, Debug, Default, Clone)]
+pub struct Obstacles {
+    list: Vec<Obstacle>,
+}
+
+impl Obstacles {
+    pub fn add(&mut self, obstacle: Obstacle) {
+        self.list.push(obstacle);
+    }
+
+    pub fn remove_at(&mut self, point: Vec2) -> Option<Obstacle> {
+        let idx: usize = self
+            .list
+            .iter()
+            .rposition(|obstacle| obstacle.contains(point))?;
+        Some(self.list.remove(idx))
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[Obstacle] {
+        &self.list
+    }
+
+    pub fn ray_hit(&self, origin: Vec2, dir: Vec2, max_distance: f32) -> Option<f32> {
+        self.list
+            .iter()
+            .filter_map(|obstacle| obstacle.ray_hit(origin, dir, max_distance))
+            .min_by(f32::total_cmp)
+    }
+
+    pub fn push_out(&self, point: Vec2, fallback: Vec2, margin: f32) -> Vec2 {
+        let mut resolved: Vec2 = point;
+
+        for _ in 0..MAX_PUSH_PASSES {
+            let mut moved: bool = false;
+            for obstacle in &self.list {
+                if let Some(outside) = obstacle.push_out(resolved, margin)
+                    && outside != resolved
+                {
+                    resolved = outside;
+                    moved = true;
+                }
+            }
+
+            if !moved {
+                return resolved;
+            }
+        }
+
+        fallback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Obstacles;
+    use crate::{Obstacle, Vec2};
+
+    #[test]
+    fn obstacles_remove_at_topmost() {
+        let mut obstacles: Obstacles = Obstacles::default();
+        obstacles.add(Obstacle::circle(Vec2::ZERO, 5.0));
+        obstacles.add(Obstacle::rect(Vec2::ZERO, Vec2::new(2.0, 2.0)));
+
+        assert_eq!(
+            obstacles.remove_at(Vec2::new(1.0, 1.0)),
+            Some(Obstacle::rect(Vec2::ZERO, Vec2::new(2.0, 2.0)))
+        );
+        assert_eq!(obstacles.as_slice().len(), 1);
+        assert_eq!(obstacles.remove_at(Vec2::new(50.0, 50.0)), None);
+    }
+
+    #[test]
+    fn obstacles_push_out_of_overlapping() {
+        let mut obstacles: Obstacles = Obstacles::default();
+        obstacles.add(Obstacle::rect(Vec2::new(9.0, -1.0), Vec2::new(30.0, 1.0)));
+        obstacles.add(Obstacle::circle(Vec2::ZERO, 10.0));
+
+        let resolved: Vec2 = obstacles.push_out(Vec2::new(5.0, 0.2), Vec2::new(-50.0, 0.0), 0.5);
+        assert_ne!(resolved, Vec2::new(-50.0, 0.0));
+        assert!(obstacles
+            .as_slice()
+            .iter()
+            .all(|obstacle| !obstacle.contains(resolved)));
+        assert_eq!(
+            obstacles.push_out(Vec2::new(50.0, 0.0), Vec2::ZERO, 0.5),
+            Vec2::new(50.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn obstacles_push_out_falls_back_when_trapped() {
+        let mut obstacles: Obstacles = Obstacles::default();
+        obstacles.add(Obstacle::rect(
+            Vec2::new(-10.0, -10.0),
+            Vec2::new(0.0, 10.0),
+        ));
+        obstacles.add(Obstacle::rect(Vec2::new(0.0, -10.0), Vec2::new(10.0, 10.0)));
+
+        let fallback: Vec2 = Vec2::new(-50.0, 0.0);
+        assert_eq!(
+            obstacles.push_out(Vec2::new(0.0, 0.0), fallback, 0.5),
+            fallback
+        );
+    }
+
+    #[test]
+    fn obstacles_nearest_ray_hit() {
+        let mut obstacles: Obstacles = Obstacles::default();
+        obstacles.add(Obstacle::circle(Vec2::new(20.0, 0.0), 1.0));
+        obstacles.add(Obstacle::circle(Vec2::new(10.0, 0.0), 1.0));
+
+        assert_eq!(
+            obstacles.ray_hit(Vec2::ZERO, Vec2::new(1.0, 0.0), 100.0),
+            Some(9.0)
+        );
+    }
+}
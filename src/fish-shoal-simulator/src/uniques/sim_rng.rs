@@ -0,0 +1,167 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use rand::{Rng, RngCore};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use shipyard::Unique;
+
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+#[derive(Unique, Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SimRng {
+    seed: u64,
+    tick: u64,
+    spawned: u64,
+}
+
+impl SimRng {
+    pub const SPAWN: u64 = 1;
+    pub const DESPAWN: u64 = 2;
+    pub const BEHAVIOR: u64 = 3;
+    pub const SWARMING: u64 = 4;
+    pub const TRAITS: u64 = 5;
+    pub const GOALS: u64 = 6;
+    pub const ALARM: u64 = 7;
+
+    pub fn new(seed: Option<u64>) -> Self {
+        Self {
+            seed: seed.unwrap_or_else(|| rand::rng().random()),
+            tick: 0,
+            spawned: 0,
+        }
+    }
+
+    #[inline]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    #[inline]
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    #[inline]
+    pub fn advance(&mut self) {
+        self.tick = self.tick.wrapping_add(1);
+    }
+
+    pub fn next_key(&mut self) -> u64 {
+        let key: u64 = self.spawned;
+        self.spawned = self.spawned.wrapping_add(1);
+        key
+    }
+
+    pub fn stream(&self, key: u64, channel: u64) -> StreamRng {
+        let mut state: u64 = mix(self.seed);
+        state = mix(state ^ key);
+        state = mix(state ^ self.tick);
+        state = mix(state ^ channel);
+        StreamRng { state }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StreamRng {
+    state: u64,
+}
+
+impl RngCore for StreamRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(GOLDEN_GAMMA);
+        mix(self.state)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for chunk in dst.chunks_mut(8) {
+            let bytes: [u8; 8] = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+// SplitMix64 finalizer, see https://prng.di.unimi.it/splitmix64.c
+#[inline]
+fn mix(value: u64) -> u64 {
+    let mut z: u64 = value.wrapping_add(GOLDEN_GAMMA);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SimRng, StreamRng};
+    use rand::{Rng, RngCore};
+
+    #[test]
+    fn sim_rng_same_seed_same_stream() {
+        let rng_a: SimRng = SimRng::new(Some(42));
+        let rng_b: SimRng = SimRng::new(Some(42));
+
+        let mut stream_a: StreamRng = rng_a.stream(7, SimRng::BEHAVIOR);
+        let mut stream_b: StreamRng = rng_b.stream(7, SimRng::BEHAVIOR);
+
+        let values_a: Vec<u64> = (0..16).map(|_| stream_a.next_u64()).collect();
+        let values_b: Vec<u64> = (0..16).map(|_| stream_b.next_u64()).collect();
+
+        assert_eq!(values_a, values_b);
+    }
+
+    #[test]
+    fn sim_rng_streams_are_independent() {
+        let mut rng: SimRng = SimRng::new(Some(42));
+
+        let first: u64 = rng.stream(0, SimRng::BEHAVIOR).next_u64();
+        assert_ne!(first, rng.stream(1, SimRng::BEHAVIOR).next_u64());
+        assert_ne!(first, rng.stream(0, SimRng::SPAWN).next_u64());
+
+        rng.advance();
+        assert_ne!(first, rng.stream(0, SimRng::BEHAVIOR).next_u64());
+    }
+
+    #[test]
+    fn sim_rng_next_key() {
+        let mut rng: SimRng = SimRng::new(Some(0));
+
+        assert_eq!(rng.next_key(), 0);
+        assert_eq!(rng.next_key(), 1);
+        assert_eq!(rng.next_key(), 2);
+    }
+
+    #[test]
+    fn sim_rng_stream_range() {
+        let rng: SimRng = SimRng::new(Some(1234));
+        let mut stream: StreamRng = rng.stream(3, SimRng::SPAWN);
+
+        for _ in 0..1_000 {
+            let value: f32 = stream.random_range(10.0..20.0);
+            assert!((10.0..20.0).contains(&value));
+        }
+
+        let mut bytes: [u8; 13] = [0; 13];
+        stream.fill_bytes(&mut bytes);
+        assert!(bytes.iter().any(|&byte| byte != 0));
+    }
+}